@@ -0,0 +1,115 @@
+// Keeps the app, its style sheets, icons and fonts, so that the calendar works offline.
+//
+// Pages are fetched from the network first, so that a new version is picked up when online.
+// Everything else is served from the cache and refreshed in the background.
+// Change the version to drop everything cached by an older one.
+
+const CACHE = "calendar-v1";
+
+const PRECACHED = [
+    "./",
+    "manifest.webmanifest",
+    "icons/icon.svg",
+    "icons/icon-192.png",
+    "icons/icon-512.png",
+];
+
+// Web fonts and icons are kept along with the files of the app.
+const FONT_ORIGINS = [
+    "https://fonts.googleapis.com",
+    "https://fonts.gstatic.com",
+];
+
+function kept(url) {
+    return url.origin === self.location.origin || FONT_ORIGINS.includes(url.origin);
+}
+
+// Files named by `url(...)` in a cached style sheet, such as the font of the icons.
+async function referenced(cache, url) {
+    const response = await cache.match(url);
+    if (!response?.headers.get("Content-Type")?.includes("text/css")) {
+        return [];
+    }
+    const css = await response.text();
+    return [...css.matchAll(/url\(\s*(["']?)([^"')]+)\1\s*\)/g)]
+        .map((match) => new URL(match[2], url))
+        .filter((url) => url.protocol.startsWith("http") && kept(url));
+}
+
+// The files of the app have hashed names, so they are found in the page linking them.
+async function precache() {
+    const cache = await caches.open(CACHE);
+    await cache.addAll(PRECACHED);
+    const page = await (await cache.match("./")).text();
+    const linked = [...page.matchAll(/(?:href|src)="([^"]+)"/g)]
+        .map((match) => new URL(match[1].replaceAll("&amp;", "&"), self.location.href))
+        .filter(kept);
+    await Promise.allSettled(linked.map((url) => cache.add(url)));
+    const inner = await Promise.all(linked.map((url) => referenced(cache, url).catch(() => [])));
+    await Promise.allSettled(inner.flat().map((url) => cache.add(url)));
+}
+
+self.addEventListener("install", (event) => {
+    event.waitUntil(precache().then(() => self.skipWaiting()));
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil(
+        caches.keys()
+            .then((keys) => Promise.all(
+                keys.filter((key) => key !== CACHE).map((key) => caches.delete(key)),
+            ))
+            .then(() => self.clients.claim()),
+    );
+});
+
+function cacheable(response) {
+    // Style sheets and fonts from other origins may be opaque, but still usable.
+    return response.ok || response.type === "opaque";
+}
+
+async function networkFirst(request) {
+    const cache = await caches.open(CACHE);
+    try {
+        const response = await fetch(request);
+        if (cacheable(response)) {
+            await cache.put(request, response.clone());
+        }
+        return response;
+    } catch (error) {
+        const cached = await cache.match(request, { ignoreSearch: true });
+        if (cached) {
+            return cached;
+        }
+        throw error;
+    }
+}
+
+async function staleWhileRevalidate(event) {
+    const cache = await caches.open(CACHE);
+    const cached = await cache.match(event.request);
+    const fetched = fetch(event.request).then(async (response) => {
+        if (cacheable(response)) {
+            await cache.put(event.request, response.clone());
+        }
+        return response;
+    });
+    if (cached) {
+        event.waitUntil(fetched.catch(() => undefined));
+        return cached;
+    }
+    return fetched;
+}
+
+self.addEventListener("fetch", (event) => {
+    const request = event.request;
+    if (request.method !== "GET") {
+        return;
+    }
+    const url = new URL(request.url);
+    if (request.mode === "navigate") {
+        event.respondWith(networkFirst(request));
+    } else if (kept(url)) {
+        event.respondWith(staleWhileRevalidate(event));
+    }
+});
@@ -1,48 +1,36 @@
-use std::{ops::Deref, rc::Rc};
+use std::{cmp::Ordering, ops::Deref, rc::Rc};
 
-use chrono::{Datelike, Local, Month, NaiveDate, Weekday};
+use chrono::{Datelike, Month, NaiveDate, Weekday};
+use gloo_events::{EventListener, EventListenerOptions};
+use gloo_timers::callback::Timeout;
+use js_sys::{Array, Intl::DateTimeFormat, Object, Reflect};
 use nongli::{
-    calendar::{Calendar, Options},
-    is_weekend,
-    iter::{Months, Weekdays},
-    language::{Language, ShortTranslate, StaticTranslate, Translate},
+    language::{Language, MonthTitle, StaticTranslate, Translate},
+    ChineseDate,
 };
-use yew::prelude::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement, KeyboardEvent, WheelEvent};
+use yew::{platform::spawn_local, prelude::*};
 
 use crate::{
-    form::{CheckboxInput, ColorInput, Form, IntInput, Select, SelectOption, StringInput},
+    clock::Clock,
+    dialogs::{
+        ExportDialog, InfoDialog, JumpDialog, PrintDialog, SettingsDialog, ShortcutsDialog,
+        StylesDialog,
+    },
+    fonts,
+    gesture::{Direction, Swipe, Wheel},
+    history::{History, HistoryAction},
+    kiosk::Kiosk,
+    local_fonts::{self, LocalFont},
+    model,
+    month::{MonthHeader, MonthTable},
+    settings::{Preferences, Settings, SettingsAction, SettingsContext},
+    shortcuts::Shortcut,
+    sizes,
+    time_zone::{self, DisplayZone},
     translations,
 };
 
-const LANGUAGES: &[Language] = &[
-    Language::English,
-    Language::ChineseSimplified,
-    Language::ChineseTraditional,
-];
-
-const GENERIC_FONTS: &[&str] = &[
-    "cursive",
-    "emoji",
-    "fangsong",
-    "fantasy",
-    "inherit",
-    "initial",
-    "kai",
-    "math",
-    "monospace",
-    "nastaliq",
-    "revert",
-    "revert-layer",
-    "sans-serif",
-    "serif",
-    "system-ui",
-    "ui-monospace",
-    "ui-rounded",
-    "ui-sans-serif",
-    "ui-serif",
-    "unset",
-];
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct YearMonth {
     year: i32,
@@ -52,8 +40,9 @@ struct YearMonth {
 enum YearMonthAction {
     NextMonth,
     PrevMonth,
-    Today,
-    #[allow(dead_code)]
+    NextYear,
+    PrevYear,
+    Today(NaiveDate),
     Set(i32, Month),
     SetYear(i32),
     SetMonth(Month),
@@ -64,6 +53,10 @@ enum Dialog {
     Jump,
     Styles,
     Settings,
+    Info(NaiveDate),
+    Shortcuts,
+    Print,
+    Export,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -72,6 +65,15 @@ struct ActiveDialog(Option<Dialog>);
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct Toggle(bool);
 
+impl From<Direction> for YearMonthAction {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Prev => Self::PrevMonth,
+            Direction::Next => Self::NextMonth,
+        }
+    }
+}
+
 impl Reducible for YearMonth {
     type Action = YearMonthAction;
     fn reduce(self: Rc<Self>, action: Self::Action) -> std::rc::Rc<Self> {
@@ -119,13 +121,38 @@ impl Reducible for YearMonth {
                     })
                 }
             }
-            Today => {
-                let today = Local::now().date_naive();
-                Rc::new(Self {
-                    year: today.year(),
-                    month: Month::try_from(today.month() as u8).unwrap(),
-                })
+            NextYear => {
+                if let Some(next_year) = self
+                    .year
+                    .checked_add(1)
+                    .filter(|next_year| NaiveDate::from_ymd_opt(*next_year, 1, 1).is_some())
+                {
+                    Rc::new(Self {
+                        year: next_year,
+                        month: self.month,
+                    })
+                } else {
+                    self
+                }
+            }
+            PrevYear => {
+                if let Some(prev_year) = self
+                    .year
+                    .checked_sub(1)
+                    .filter(|prev_year| NaiveDate::from_ymd_opt(*prev_year, 1, 1).is_some())
+                {
+                    Rc::new(Self {
+                        year: prev_year,
+                        month: self.month,
+                    })
+                } else {
+                    self
+                }
             }
+            Today(today) => Rc::new(Self {
+                year: today.year(),
+                month: Month::try_from(today.month() as u8).unwrap(),
+            }),
             Set(year, month) => Rc::new(Self { year, month }),
             SetYear(year) => {
                 if NaiveDate::from_ymd_opt(year, 1, 1).is_some() {
@@ -167,563 +194,651 @@ impl Reducible for Toggle {
     }
 }
 
+fn focus_day(date: NaiveDate) {
+    if let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| {
+            document
+                .query_selector(&format!("td[data-date=\"{date}\"]"))
+                .ok()
+                .flatten()
+        })
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        let _ = element.focus();
+    }
+}
+
+/// The date of today in `time_zone`, refreshed at midnight and whenever the page becomes visible.
+#[hook]
+fn use_today(time_zone: DisplayZone) -> NaiveDate {
+    let today = use_state_eq(|| time_zone.today());
+    let wakeups = use_state(|| 0u32);
+    {
+        let today = today.clone();
+        let wakeups = wakeups.clone();
+        use_effect_with((time_zone, *wakeups), move |(time_zone, _)| {
+            let time_zone = *time_zone;
+            today.set(time_zone.today());
+            let today1 = today.clone();
+            let timeout = Timeout::new(
+                time_zone
+                    .millis_until_tomorrow()
+                    .saturating_add(1000)
+                    .min(time_zone::MAX_TIMEOUT),
+                move || {
+                    today1.set(time_zone.today());
+                    wakeups.set(*wakeups + 1);
+                },
+            );
+            let listener = web_sys::window()
+                .and_then(|window| window.document())
+                .map(|document| {
+                    EventListener::new(&document, "visibilitychange", move |_| {
+                        today.set(time_zone.today())
+                    })
+                });
+            move || {
+                drop(timeout);
+                drop(listener);
+            }
+        });
+    }
+    *today
+}
+
+fn window_is_mobile() -> bool {
+    // There is no window when pre-rendering.
+    cfg!(target_arch = "wasm32")
+        && web_sys::window()
+            .and_then(|window| window.inner_width().ok())
+            .and_then(|width| width.as_f64())
+            .is_some_and(|width| width < sizes::MOBILE_WIDTH)
+}
+
+/// IANA name of the time zone of the browser.
+fn browser_time_zone() -> Option<String> {
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let options = DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+    Reflect::get(&options, &"timeZone".into()).ok()?.as_string()
+}
+
+/// Whether the window is narrow enough for the compact layout, following resizes and rotations.
+#[hook]
+fn use_is_mobile() -> bool {
+    let is_mobile = use_state_eq(window_is_mobile);
+    {
+        let is_mobile = is_mobile.clone();
+        use_effect_with((), move |_| {
+            let listeners = web_sys::window().map(|window| {
+                ["resize", "orientationchange"].map(|event| {
+                    let is_mobile = is_mobile.clone();
+                    EventListener::new(&window, event, move |_| is_mobile.set(window_is_mobile()))
+                })
+            });
+            move || drop(listeners)
+        });
+    }
+    *is_mobile
+}
+
+/// Settings to start with, which pre-rendered pages carry to the app hydrating them.
+#[derive(Clone, Copy, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// The month shown, the current one if `None`.
+    #[prop_or_default]
+    pub month: Option<(i32, Month)>,
+    #[prop_or(Language::English)]
+    pub language: Language,
+    #[prop_or_default]
+    pub enable_chinese: bool,
+    #[prop_or_default]
+    pub start_on_monday: bool,
+    #[prop_or_default]
+    pub show_week_numbers: bool,
+    #[prop_or(true)]
+    pub highlight_today: bool,
+}
+
+impl Default for Props {
+    fn default() -> Self {
+        Self {
+            month: None,
+            language: Language::English,
+            enable_chinese: false,
+            start_on_monday: false,
+            show_week_numbers: false,
+            highlight_today: true,
+        }
+    }
+}
+
 #[function_component(App)]
-pub fn app() -> Html {
-    let today = chrono::Local::now().date_naive();
-    let is_mobile = web_sys::window()
-        .and_then(|window| window.inner_width().ok())
-        .and_then(|width| width.as_f64())
-        .is_some_and(|width| width < 768.0);
+pub fn app(props: &Props) -> Html {
+    let settings = use_reducer_eq(|| Settings {
+        preferences: History::new(Preferences {
+            language: props.language,
+            enable_chinese: props.enable_chinese,
+            start_on_monday: props.start_on_monday,
+            show_week_numbers: props.show_week_numbers,
+            highlight_today: props.highlight_today,
+            ..Preferences::default()
+        }),
+        ..Settings::new(DisplayZone(None).today().year())
+    });
+    let language = settings.language;
+    let time_zone = settings.time_zone;
+    let styles = &settings.styles;
+    let today = use_today(time_zone);
+    let is_mobile = use_is_mobile();
 
-    let year_month = use_reducer_eq(|| YearMonth {
-        year: today.year(),
-        month: Month::try_from(today.month() as u8).unwrap(),
+    let year_month = use_reducer_eq(|| {
+        let (year, month) = props
+            .month
+            .unwrap_or_else(|| (today.year(), Month::try_from(today.month() as u8).unwrap()));
+        YearMonth { year, month }
     });
     let active_dialog = use_reducer_eq(|| ActiveDialog(None));
+    let kiosk = use_memo((), |_| Kiosk::from_location());
+    // A pre-rendered page knows neither the date nor the query string of the browser, so what
+    // depends on them is only added once the page has been hydrated, keeping the trees alike.
+    let hydrated = use_state_eq(|| false);
+    {
+        let hydrated = hydrated.setter();
+        use_effect_with((), move |_| hydrated.set(true));
+    }
+    let kiosk_mode = *hydrated && kiosk.is_some();
+    let kiosk_page = use_state_eq(|| 0usize);
     let show_more = use_reducer_eq(|| Toggle(false));
+    let focused_date = use_state_eq(|| Option::<NaiveDate>::None);
+    let printing_range = use_state_eq(|| false);
+    let uploaded_fonts = use_state_eq(Vec::<LocalFont>::new);
+    let installed_fonts = use_state_eq(|| Rc::<[Rc<str>]>::from([]));
+    // Touch screens are not only found on narrow windows, so swiping works in every layout.
+    let swipe = use_mut_ref(Swipe::default);
+    let body_ref = use_node_ref();
 
-    let color_text = use_state_eq(|| Rc::<str>::from("#111111"));
-    let color_theme = use_state_eq(|| Rc::<str>::from("#0000ff"));
-    let color_year = use_state_eq(|| Rc::<str>::from("#808080"));
-    let color_month = use_state_eq(|| Rc::<str>::from("#808080"));
-    let color_today_text = use_state_eq(|| Rc::<str>::from("#ffffff"));
-    let color_weekend = use_state_eq(|| Rc::<str>::from("#ff0000"));
-    let color_week_number = use_state(|| Rc::<str>::from("#808080"));
-    let color_festival = use_state(|| Rc::<str>::from("inherit"));
-    let color_solar_term = use_state_eq(|| Rc::<str>::from("inherit"));
-    let font = use_state_eq(|| Rc::<str>::from("sans-serif"));
-    let download_fonts = use_state_eq(|| true);
-    let size_cell_width = use_state_eq(|| Rc::<str>::from(if is_mobile { "48px" } else { "96px" }));
-    let size_cell_height =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "48px" } else { "96px" }));
-    let size_header_height =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "48px" } else { "96px" }));
-    let size_text = use_state_eq(|| Rc::<str>::from(if is_mobile { "16px" } else { "24px" }));
-    let size_text_year = use_state_eq(|| Rc::<str>::from(if is_mobile { "24px" } else { "48px" }));
-    let size_text_month = use_state_eq(|| Rc::<str>::from(if is_mobile { "16px" } else { "32px" }));
-    let size_text_weekday =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "8px" } else { "16px" }));
-    let size_text_week_number =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "8px" } else { "16px" }));
-    let size_text_chinese =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "8px" } else { "16px" }));
-    let size_year_margin =
-        use_state_eq(|| Rc::<str>::from(if is_mobile { "32px" } else { "64px" }));
+    let slide = {
+        let previous_year_month = use_mut_ref(|| *year_month);
+        *use_memo(*year_month, |year_month| {
+            let previous = previous_year_month.replace(*year_month);
+            let key =
+                |year_month: &YearMonth| (year_month.year, year_month.month.number_from_month());
+            match key(year_month).cmp(&key(&previous)) {
+                Ordering::Greater => Some("slide-next"),
+                Ordering::Less => Some("slide-prev"),
+                Ordering::Equal => None,
+            }
+        })
+    };
+
+    {
+        let previous_today = use_mut_ref(|| today);
+        let year_month_dispatcher = year_month.dispatcher();
+        let year_month = *year_month;
+        let auto_advance = settings.auto_advance;
+        use_effect_with(today, move |today| {
+            let previous_today = previous_today.replace(*today);
+            let month_changed =
+                (previous_today.year(), previous_today.month()) != (today.year(), today.month());
+            if auto_advance
+                && month_changed
+                && year_month.year == previous_today.year()
+                && year_month.month.number_from_month() == previous_today.month()
+            {
+                year_month_dispatcher.dispatch(YearMonthAction::Today(*today));
+            }
+        });
+    }
+    {
+        let year_month_dispatcher = year_month.dispatcher();
+        let kiosk_page_setter = kiosk_page.setter();
+        use_effect_with(
+            (kiosk.clone(), today, *kiosk_page),
+            move |(kiosk, today, kiosk_page)| {
+                let timeout = kiosk.as_ref().as_ref().and_then(|kiosk| {
+                    let page = kiosk_page % kiosk.months.len();
+                    if let Some((year, month)) = kiosk.months[page].resolve(*today) {
+                        year_month_dispatcher.dispatch(YearMonthAction::Set(year, month));
+                    }
+                    let next_page = (page + 1) % kiosk.months.len();
+                    (kiosk.months.len() > 1).then(|| {
+                        Timeout::new(kiosk.interval.saturating_mul(1000), move || {
+                            kiosk_page_setter.set(next_page)
+                        })
+                    })
+                });
+                move || drop(timeout)
+            },
+        );
+    }
 
-    let language_index = use_state_eq(|| 0u32);
-    let enable_chinese = use_state_eq(|| false);
-    let start_on_monday = use_state_eq(|| false);
-    let show_week_numbers = use_state_eq(|| false);
-    let highlight_today = use_state_eq(|| true);
+    {
+        let body_ref = body_ref.clone();
+        let year_month_dispatcher = year_month.dispatcher();
+        use_effect_with(settings.wheel_navigation, move |wheel_navigation| {
+            // Yew listens to wheel events passively, so they could not be kept from scrolling.
+            let listener = body_ref
+                .cast::<HtmlElement>()
+                .filter(|_| *wheel_navigation)
+                .map(|body| {
+                    let mut wheel = Wheel::default();
+                    EventListener::new_with_options(
+                        &body,
+                        "wheel",
+                        EventListenerOptions::enable_prevent_default(),
+                        move |event| {
+                            if let Some(direction) = event
+                                .dyn_ref::<WheelEvent>()
+                                .and_then(|event| wheel.scroll(event))
+                            {
+                                year_month_dispatcher.dispatch(direction.into());
+                            }
+                        },
+                    )
+                });
+            move || drop(listener)
+        });
+    }
 
-    let show_today_button =
-        today.year() != year_month.year || today.month() != year_month.month.number_from_month();
-    let language = LANGUAGES
-        .get(*language_index as usize)
-        .copied()
-        .unwrap_or(Language::English);
-    let options = Options {
-        language,
-        enable_chinese: *enable_chinese,
-        start_on_monday: *start_on_monday,
-        week_number: *show_week_numbers,
-        color: false,
+    let start_of_week = if settings.start_on_monday {
+        Weekday::Mon
+    } else {
+        Weekday::Sun
     };
-    let calendar = use_memo(
-        (today, *year_month, options, *highlight_today),
-        |(today, year_month, options, highlight_today)| {
-            Calendar::new(
-                year_month.year,
-                year_month.month,
-                highlight_today.then_some(*today),
-                *options,
-            )
-            .unwrap()
-        },
-    );
+
+    {
+        let year_month_dispatcher = year_month.dispatcher();
+        let active_dialog_dispatcher = active_dialog.dispatcher();
+        let focused_date_setter = focused_date.setter();
+        let settings_dispatcher = settings.dispatcher();
+        use_effect_with(
+            (today, *year_month, start_of_week, kiosk.is_none()),
+            move |(today, year_month, start_of_week, enabled)| {
+                let (today, year_month, start_of_week) = (*today, *year_month, *start_of_week);
+                let listener = web_sys::window().filter(|_| *enabled).map(|window| {
+                    EventListener::new(&window, "keydown", move |event| {
+                        let Some(event) = event.dyn_ref::<KeyboardEvent>() else {
+                            return;
+                        };
+                        let Some(shortcut) = Shortcut::from_event(event, start_of_week) else {
+                            return;
+                        };
+                        event.prevent_default();
+                        match shortcut {
+                            Shortcut::PrevMonth => {
+                                year_month_dispatcher.dispatch(YearMonthAction::PrevMonth)
+                            }
+                            Shortcut::NextMonth => {
+                                year_month_dispatcher.dispatch(YearMonthAction::NextMonth)
+                            }
+                            Shortcut::PrevYear => {
+                                year_month_dispatcher.dispatch(YearMonthAction::PrevYear)
+                            }
+                            Shortcut::NextYear => {
+                                year_month_dispatcher.dispatch(YearMonthAction::NextYear)
+                            }
+                            Shortcut::Today => {
+                                year_month_dispatcher.dispatch(YearMonthAction::Today(today))
+                            }
+                            Shortcut::Jump => active_dialog_dispatcher.dispatch(Some(Dialog::Jump)),
+                            Shortcut::Styles => {
+                                active_dialog_dispatcher.dispatch(Some(Dialog::Styles))
+                            }
+                            Shortcut::Settings => {
+                                active_dialog_dispatcher.dispatch(Some(Dialog::Settings))
+                            }
+                            Shortcut::Help => {
+                                active_dialog_dispatcher.dispatch(Some(Dialog::Shortcuts))
+                            }
+                            Shortcut::Close => active_dialog_dispatcher.dispatch(None),
+                            Shortcut::Undo => settings_dispatcher
+                                .dispatch(SettingsAction::Preferences(HistoryAction::Undo)),
+                            Shortcut::Redo => settings_dispatcher
+                                .dispatch(SettingsAction::Preferences(HistoryAction::Redo)),
+                            Shortcut::Print => {
+                                if let Some(window) = web_sys::window() {
+                                    let _ = window.print();
+                                }
+                            }
+                            Shortcut::Focus(date) => {
+                                if date.year() == year_month.year
+                                    && date.month() == year_month.month.number_from_month()
+                                {
+                                    focus_day(date);
+                                } else if let Ok(month) = Month::try_from(date.month() as u8) {
+                                    year_month_dispatcher
+                                        .dispatch(YearMonthAction::Set(date.year(), month));
+                                }
+                                focused_date_setter.set(Some(date));
+                            }
+                            Shortcut::Activate => {
+                                if let Some(element) = event
+                                    .target()
+                                    .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                                {
+                                    element.click();
+                                }
+                            }
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+        );
+    }
+    {
+        let printing_range_setter = printing_range.setter();
+        use_effect_with(*printing_range, move |printing_range| {
+            let listener = web_sys::window().filter(|_| *printing_range).map(|window| {
+                let listener = EventListener::once(&window, "afterprint", move |_| {
+                    printing_range_setter.set(false)
+                });
+                let _ = window.print();
+                listener
+            });
+            move || drop(listener)
+        });
+    }
+    use_effect_with(*focused_date, |focused_date| {
+        if let Some(date) = focused_date {
+            focus_day(*date);
+        }
+    });
+    let focus_target = [*focused_date, Some(today)]
+        .into_iter()
+        .flatten()
+        .find(|date| {
+            date.year() == year_month.year && date.month() == year_month.month.number_from_month()
+        })
+        .or_else(|| {
+            NaiveDate::from_ymd_opt(year_month.year, year_month.month.number_from_month(), 1)
+        });
+
+    let show_today_button = *hydrated
+        && (today.year() != year_month.year
+            || today.month() != year_month.month.number_from_month());
+    let options = settings.options();
+    let month_title = MonthTitle {
+        year: year_month.year,
+        month: year_month.month,
+        enable_chinese: settings.enable_chinese,
+    }
+    .translate_to_string(language);
+    use_effect_with(language, |language| {
+        if let Some(root) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+        {
+            let _ = root.set_attribute("lang", translations::language_tag(*language));
+        }
+    });
+    {
+        let uploaded_fonts_setter = uploaded_fonts.setter();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let Ok(fonts) = local_fonts::load().await else {
+                    return;
+                };
+                for font in &fonts {
+                    let _ = local_fonts::register(font).await;
+                }
+                uploaded_fonts_setter.set(fonts);
+            });
+        });
+    }
+    let uploaded_families = uploaded_fonts
+        .iter()
+        .map(|font| font.family.clone())
+        .collect::<Rc<[_]>>();
     let css_import = use_memo(
-        (font.deref().clone(), *download_fonts),
-        |(font, download_fonts)| {
+        (
+            styles.fonts.clone(),
+            styles.download_fonts,
+            uploaded_families,
+            installed_fonts.deref().clone(),
+        ),
+        |(fonts, download_fonts, uploaded_families, installed_families)| {
             if !download_fonts {
                 return String::new();
             }
-            let fonts = font
-                .split(',')
-                .map(|s| s.trim().trim_matches('"'))
-                .filter(|s| !GENERIC_FONTS.contains(&s.to_lowercase().as_str()))
-                .flat_map(|s| {
-                    "&family="
-                        .chars()
-                        .chain(s.chars().map(|ch| if ch == ' ' { '+' } else { ch }))
-                })
-                .collect::<String>();
-            if fonts.is_empty() {
-                String::new()
-            } else {
-                format!("@import url(\"https://fonts.googleapis.com/css2?display=swap{fonts}\");")
-            }
+            fonts::google_import(fonts.families().into_iter().filter(|family| {
+                !uploaded_families
+                    .iter()
+                    .chain(installed_families.iter())
+                    .any(|local| local.deref() == *family)
+            }))
         },
     );
 
+    let main_ref = use_node_ref();
     let active_dialog_value = active_dialog.0;
+    let navigate = {
+        let year_month_dispatcher = year_month.dispatcher();
+        Callback::from(move |action| year_month_dispatcher.dispatch(action))
+    };
+    let open_dialog = {
+        let active_dialog_dispatcher = active_dialog.dispatcher();
+        Callback::from(move |dialog| active_dialog_dispatcher.dispatch(dialog))
+    };
+    let close_dialog = open_dialog.reform(|()| None);
+    let dialog_button = |dialog: Dialog, title: &dyn StaticTranslate, icon: &'static str| {
+        let title = title.static_translate(language);
+        html! { <button
+            { title }
+            aria-label={ title }
+            class={ classes!(
+                "material-symbols-outlined",
+                (active_dialog_value == Some(dialog)).then_some("active"),
+            ) }
+            onclick={ open_dialog.reform(move |_| Some(dialog)) }
+        >
+            { icon }
+        </button> }
+    };
 
-    let year_month_dispatcher = year_month.dispatcher();
-    let year_month_dispatcher1 = year_month.dispatcher();
-    let year_month_dispatcher2 = year_month.dispatcher();
-    let year_month_dispatcher3 = year_month.dispatcher();
-    let year_month_dispatcher4 = year_month.dispatcher();
-    let active_dialog_dispatcher = active_dialog.dispatcher();
-    let active_dialog_dispatcher1 = active_dialog.dispatcher();
-    let active_dialog_dispatcher2 = active_dialog.dispatcher();
-    let active_dialog_dispatcher3 = active_dialog.dispatcher();
-
-    let color_text_setter = color_text.setter();
-    let color_theme_setter = color_theme.setter();
-    let color_year_setter = color_year.setter();
-    let color_month_setter = color_month.setter();
-    let color_today_text_setter = color_today_text.setter();
-    let color_weekend_setter = color_weekend.setter();
-    let color_week_number_setter = color_week_number.setter();
-    let color_festival_setter = color_festival.setter();
-    let color_solar_term_setter = color_solar_term.setter();
-    let font_setter = font.setter();
-    let download_fonts_setter = download_fonts.setter();
-    let size_cell_width_setter = size_cell_width.setter();
-    let size_cell_height_setter = size_cell_height.setter();
-    let size_header_height_setter = size_header_height.setter();
-    let size_text_setter = size_text.setter();
-    let size_text_year_setter = size_text_year.setter();
-    let size_text_month_setter = size_text_month.setter();
-    let size_text_weekday_setter = size_text_weekday.setter();
-    let size_text_week_number_setter = size_text_week_number.setter();
-    let size_text_chinese_setter = size_text_chinese.setter();
-    let size_year_margin_setter = size_year_margin.setter();
-
-    let language_setter = language_index.setter();
-    let enable_chinese_setter = enable_chinese.setter();
-    let start_on_monday_setter = start_on_monday.setter();
-    let show_week_numbers_setter = show_week_numbers.setter();
-    let highlight_today_setter = highlight_today.setter();
-
-    let show_more_dispatcher = show_more.dispatcher();
-
-    html! { <>
-            if !css_import.is_empty() {
-                <style>{ css_import }</style>
-            }
-            <style>{
-                format!("
-            :root {{
-                --color-text: {color_text};
-                --color-theme: {color_theme};
-                --color-year: {color_year};
-                --color-month: {color_month};
-                --color-today-text: {color_today_text};
-                --color-weekend: {color_weekend};
-                --color-week-number: {color_week_number};
-                --color-festival: {color_festival};
-                --color-solar-term: {color_solar_term};
-                --size-cell-width: {size_cell_width};
-                --size-cell-height: {size_cell_height};
-                --size-header-height: {size_header_height};
-                --size-text: {size_text};
-                --size-text-weekday: {size_text_weekday};
-                --size-text-week-number: {size_text_week_number};
-                --size-text-chinese: {size_text_chinese};
-                --size-text-month: {size_text_month};
-                --size-text-year: {size_text_year};
-                --size-year-margin: {size_year_margin};
-                --font-family: {font};
-            }}
-            ",
-            color_text = color_text.deref(),
-            color_theme = color_theme.deref(),
-            color_year = color_year.deref(),
-            color_month = color_month.deref(),
-            color_today_text = color_today_text.deref(),
-            color_weekend = color_weekend.deref(),
-            color_week_number = color_week_number.deref(),
-            color_festival = color_festival.deref(),
-            color_solar_term = color_solar_term.deref(),
-            font = font.deref(),
-            size_cell_width = size_cell_width.deref(),
-            size_cell_height = size_cell_height.deref(),
-            size_header_height = size_header_height.deref(),
-            size_text = size_text.deref(),
-            size_text_weekday = size_text_weekday.deref(),
-            size_text_week_number = size_text_week_number.deref(),
-            size_text_chinese = size_text_chinese.deref(),
-            size_text_month = size_text_month.deref(),
-            size_text_year = size_text_year.deref(),
-            size_year_margin = size_year_margin.deref(),
-        )
+    html! { <ContextProvider<SettingsContext> context={ settings.clone() }>
+        if !css_import.is_empty() {
+            <style>{ css_import.deref().clone() }</style>
+        }
+        <style>{
+            format!(":root {{ {} }}", model::root_variables(&styles.palette, &styles.fonts))
         }</style>
-        <main>
-            <div class="header">
-                <div class="side left">{ year_month.month.name() }</div>
-                <div class="year">{ year_month.year }</div>
-                <div class="side right">
-                    if language != Language::English {
-                        { year_month.month.translate_to_string(language) }
+        <style>{ styles.sizes.css(is_mobile) }</style>
+        <style>{ settings.print_setup.css() }</style>
+        <main ref={ main_ref.clone() } class={ classes!(
+            kiosk_mode.then_some("kiosk"),
+            settings.print_setup.scale_to_fit.then_some("fit-page"),
+        ) }>
+            <MonthHeader year={ year_month.year } month={ year_month.month } { language } />
+            if let Some(label) = time_zone.label(browser_time_zone().as_deref()) {
+                <div class="time-zone">{ label }</div>
+            }
+            if kiosk_mode {
+                <div class="kiosk-status">
+                    <span>{ translations::FullDate(today).translate_to_string(language) }</span>
+                    if let Some(chinese_date) = ChineseDate::from_gregorian(&today) {
+                        <span>{ chinese_date.translate_to_string(language) }</span>
                     }
+                    <Clock { time_zone } />
                 </div>
-            </div>
-            <div class="body">
-                <table class="calendar">
-                    <tr>
-                        if *show_week_numbers { <th></th> }
-                        {
-                            for Weekdays(if *start_on_monday { Weekday::Mon } else { Weekday::Sun })
-                                .take(7)
-                                .map(|weekday| html! {
-                                    <th class={classes!(
-                                        "weekday",
-                                        is_weekend(weekday).then_some("weekend"),
-                                    )}>
-                                        { weekday.short().translate_to_string(language) }
-                                    </th>
-                                })
+            }
+            <div class="visually-hidden" aria-live="polite">{ month_title }</div>
+            <div
+                class="body"
+                ref={ body_ref }
+                ontouchstart={ {
+                    let swipe = swipe.clone();
+                    move |event: TouchEvent| swipe.borrow_mut().start(&event)
+                } }
+                ontouchcancel={ {
+                    let swipe = swipe.clone();
+                    move |_| swipe.borrow_mut().cancel()
+                } }
+                ontouchend={ {
+                    let navigate = navigate.clone();
+                    move |event: TouchEvent| {
+                        if let Some(direction) = swipe.borrow_mut().end(&event) {
+                            navigate.emit(direction.into());
                         }
-                    </tr>
-                    {
-                        for calendar.iter().map(|(week_number, row)| html! {
-                            <tr>
-                                if *show_week_numbers {
-                                    <th class="week-number">{ week_number }</th>
-                                }
-                                {
-                                    for row.map(|cell| html! {
-                                        if let Some(cell) = cell {
-                                            <td class={ classes!(
-                                                cell.today.then_some("today"),
-                                                cell.weekend.then_some("weekend"),
-                                            ) }>
-                                                <div class="day">{ cell.date.day() }</div>
-                                                if let Some(festival) = cell.festival {
-                                                    <div class="chinese festival">{
-                                                        festival.static_translate(language)
-                                                    }</div>
-                                                } else if let Some(solar_term) = cell.solar_term {
-                                                    <div class="chinese solar-term">{
-                                                        solar_term.static_translate(language)
-                                                    }</div>
-                                                } else if let Some(chinese) = cell.chinese_date {
-                                                    <div class="chinese">{
-                                                        chinese
-                                                            .short()
-                                                            .translate_to_string(language)
-                                                    }</div>
-                                                } else if *enable_chinese {
-                                                    <div class="chinese"></div>
-                                                }
-                                            </td>
-                                        } else {
-                                            <td></td>
-                                        }
-                                    })
-                                }
-                            </tr>
-                        })
                     }
-                </table>
+                } }
+            >
+                <MonthTable
+                    key={ format!("{}-{}", year_month.year, year_month.month.number_from_month()) }
+                    year={ year_month.year }
+                    month={ year_month.month }
+                    { today }
+                    highlight_today={ settings.highlight_today }
+                    { options }
+                    { time_zone }
+                    class={ classes!(slide) }
+                    { focus_target }
+                    onselect={ open_dialog.reform(|date| Some(Dialog::Info(date))) }
+                />
             </div>
         </main>
+        if *printing_range {
+            <div class={ classes!(
+                "print-range",
+                settings.print_setup.scale_to_fit.then_some("fit-page"),
+            ) }>
+            {
+                for model::month_range(settings.print_range.0, settings.print_range.1)
+                    .into_iter()
+                    .map(|(year, month)| html! { <section class="page">
+                        <MonthHeader { year } { month } { language } />
+                        <div class="body">
+                            <MonthTable
+                                { year }
+                                { month }
+                                { today }
+                                highlight_today={ settings.highlight_today }
+                                { options }
+                                { time_zone }
+                            />
+                        </div>
+                    </section> })
+            }
+            </div>
+        }
         if let Some(dialog) = active_dialog_value {{
             match dialog {
-                Dialog::Jump => html! { <div class="dialog">
-                    <div class="title">{ translations::Jump.static_translate(language) }</div>
-                    <Form>
-                        <IntInput
-                            name={ translations::Year.static_translate(language) }
-                            min={ Some(-262143) }
-                            max={ Some(262142) }
-                            value={ year_month.year }
-                            onchange={ move |value| {
-                                year_month_dispatcher3
-                                    .dispatch(YearMonthAction::SetYear(value));
-                        } }/>
-                        <Select
-                            name={ translations::Month.static_translate(language) }
-                            value={ year_month.month as u32 }
-                            onchange={move |value| {
-                                if let Ok(month) = Month::try_from(value as u8 + 1) {
-                                    year_month_dispatcher4.dispatch(
-                                        YearMonthAction::SetMonth(month)
-                                    );
-                                }
-                            }}
-                        >
-                        {
-                            for Months(Month::January).take(12).map(|month| html_nested! {
-                                <SelectOption>
-                                    { month.translate_to_string(language) }
-                                </SelectOption>
-                            })
-                        }
-                        </Select>
-                    </Form>
-                </div> },
-                Dialog::Styles => html! {<div class="dialog">
-                    <div class="title">{ translations::Styles.static_translate(language) }</div>
-                    <Form>
-                        <ColorInput
-                            name={ translations::TextColor.static_translate(language) }
-                            value={ color_text.deref().clone() }
-                            onchange={ move |value| color_text_setter.set(Rc::from(value)) }
-                        />
-                        <ColorInput
-                            name={ translations::ThemeColor.static_translate(language) }
-                            value={ color_theme.deref().clone() }
-                            onchange={ move |value| color_theme_setter.set(Rc::from(value)) }
-                        />
-                        <ColorInput
-                            name={ translations::YearColor.static_translate(language) }
-                            value={ color_year.deref().clone() }
-                            onchange={ move |value| color_year_setter.set(Rc::from(value)) }
-                        />
-                        <ColorInput
-                            name={ translations::MonthColor.static_translate(language) }
-                            value={ color_month.deref().clone() }
-                            onchange={ move |value| color_month_setter.set(Rc::from(value)) }
-                        />
-                        <ColorInput
-                            name={ translations::TodayTextColor.static_translate(language) }
-                            value={ color_today_text.deref().clone() }
-                            onchange={move |value| {
-                                color_today_text_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <ColorInput
-                            name={ translations::WeekendColor.static_translate(language) }
-                            value={ color_weekend.deref().clone() }
-                            onchange={ move |value| color_weekend_setter.set(Rc::from(value)) }
-                        />
-                        <ColorInput
-                            name={ translations::WeekNumberColor.static_translate(language) }
-                            value={ color_week_number.deref().clone() }
-                            onchange={ move |value| {
-                                color_week_number_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <ColorInput
-                            name={ translations::FestivalColor.static_translate(language) }
-                            value={ color_festival.deref().clone() }
-                            onchange={ move |value| {
-                                color_festival_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <ColorInput
-                            name={ translations::SolarTermColor.static_translate(language) }
-                            value={ color_solar_term.deref().clone() }
-                            onchange={ move |value| {
-                                color_solar_term_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::Font.static_translate(language) }
-                            value={ font.deref().clone() }
-                            onchange={ move |value| font_setter.set(Rc::from(value)) }
-                        />
-                        <CheckboxInput
-                            name={
-                                translations::DownloadFontsAutomatically
-                                .static_translate(language)
-                            }
-                            checked={ *download_fonts }
-                            onchange={ move |checked| download_fonts_setter.set(checked) }
-                        />
-                        <StringInput
-                            name={ translations::CellWidth.static_translate(language) }
-                            value={ size_cell_width.deref().clone() }
-                            onchange={ move |value| {
-                                size_cell_width_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::CellHeight.static_translate(language) }
-                            value={ size_cell_height.deref().clone() }
-                            onchange={ move |value| {
-                                size_cell_height_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::HeaderHeight.static_translate(language) }
-                            value={ size_header_height.deref().clone() }
-                            onchange={ {
-                                move |value| size_header_height_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::YearMargin.static_translate(language) }
-                            value={ size_year_margin.deref().clone() }
-                            onchange={ move |value| {
-                                size_year_margin_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::TextSize.static_translate(language) }
-                            value={ size_text.deref().clone() }
-                            onchange={ move |value| size_text_setter.set(Rc::from(value)) }
-                        />
-                        <StringInput
-                            name={ translations::WeekdayTextSize.static_translate(language) }
-                            value={ size_text_weekday.deref().clone() }
-                            onchange={ move |value| size_text_weekday_setter.set(Rc::from(value)) }
-                        />
-                        <StringInput
-                            name={ translations::WeekNumberTextSize.static_translate(language) }
-                            value={ size_text_week_number.deref().clone() }
-                            onchange={ move |value| {
-                                size_text_week_number_setter.set(Rc::from(value))
-                            } }
-                        />
-                        <StringInput
-                            name={ translations::ChineseTextSize.static_translate(language) }
-                            value={ size_text_chinese.deref().clone() }
-                            onchange={ move |value| size_text_chinese_setter.set(Rc::from(value)) }
-                        />
-                        <StringInput
-                            name={ translations::YearTextSize.static_translate(language) }
-                            value={ size_text_year.deref().clone() }
-                            onchange={ move |value| size_text_year_setter.set(Rc::from(value)) }
-                        />
-                        <StringInput
-                            name={ translations::MonthTextSize.static_translate(language) }
-                            value={ size_text_month.deref().clone() }
-                            onchange={ move |value| {
-                                size_text_month_setter.set(Rc::from(value))
-                            } }
-                        />
-                    </Form>
-                </div> },
-                Dialog::Settings => html! { <div class="dialog">
-                    <div class="title">{ translations::Settings.static_translate(language) }</div>
-                    <Form>
-                        <Select
-                            name={ translations::Language.static_translate(language) }
-                            value={ *language_index }
-                            onchange={ move |value| language_setter.set(value) }
-                        >
-                            <SelectOption>{"English"}</SelectOption>
-                            <SelectOption>{"简体中文"}</SelectOption>
-                            <SelectOption>{"繁體中文"}</SelectOption>
-                        </Select>
-                        <CheckboxInput
-                            name={ translations::EnableChineseCalendar.static_translate(language) }
-                            checked={ *enable_chinese }
-                            onchange={ move |checked| enable_chinese_setter.set(checked) }
-                        />
-                        <CheckboxInput
-                            name={ translations::StartOnMonday.static_translate(language) }
-                            checked={ *start_on_monday }
-                            onchange={ move |checked| start_on_monday_setter.set(checked) }
-                        />
-                        <CheckboxInput
-                            name={ translations::ShowWeekNumbers.static_translate(language) }
-                            checked={ *show_week_numbers }
-                            onchange={ move |checked| show_week_numbers_setter.set(checked) }
-                        />
-                        <CheckboxInput
-                            name={ translations::HighlightToday.static_translate(language) }
-                            checked={ *highlight_today }
-                            onchange={ move |checked| highlight_today_setter.set(checked) }
-                        />
-                    </Form>
-                </div> }
-            } }
-        }
-        <div class="corner-buttons">
-            if show_today_button {
-                <button
-                    title={ translations::Today.static_translate(language) }
-                    class="material-symbols-outlined"
-                    onclick={ move |_| year_month_dispatcher2.dispatch(YearMonthAction::Today) }
-                >
-                    {"today"}
-                </button>
+                Dialog::Jump => html! { <JumpDialog
+                    key="jump"
+                    year={ year_month.year }
+                    month={ year_month.month }
+                    onyear={ navigate.reform(YearMonthAction::SetYear) }
+                    onmonth={ navigate.reform(YearMonthAction::SetMonth) }
+                    onclose={ close_dialog }
+                /> },
+                Dialog::Styles => html! { <StylesDialog
+                    key="styles"
+                    uploaded_fonts={ uploaded_fonts.clone() }
+                    installed_fonts={ installed_fonts.clone() }
+                    { is_mobile }
+                    onclose={ close_dialog }
+                /> },
+                Dialog::Settings => html! {
+                    <SettingsDialog key="settings" onclose={ close_dialog } />
+                },
+                Dialog::Shortcuts => html! {
+                    <ShortcutsDialog key="shortcuts" onclose={ close_dialog } />
+                },
+                Dialog::Print => html! { <PrintDialog
+                    key="print"
+                    onprintrange={ {
+                        let printing_range_setter = printing_range.setter();
+                        Callback::from(move |()| printing_range_setter.set(true))
+                    } }
+                    onclose={ close_dialog }
+                /> },
+                Dialog::Export => html! { <ExportDialog
+                    key="export"
+                    year={ year_month.year }
+                    month={ year_month.month }
+                    { today }
+                    { is_mobile }
+                    css_import={ AttrValue::from(css_import.deref().clone()) }
+                    main_ref={ main_ref.clone() }
+                    onclose={ close_dialog }
+                /> },
+                Dialog::Info(date) => html! {
+                    <InfoDialog key={ date.to_string() } { date } onclose={ close_dialog } />
+                },
             }
-            <button
-                title={ translations::PrevMonth.static_translate(language) }
-                class="material-symbols-outlined"
-                disabled={
-                    year_month.month == Month::January
-                    && NaiveDate::from_ymd_opt(year_month.year - 1, 1, 1).is_none()
-                }
-                onclick={ move |_| year_month_dispatcher.dispatch(YearMonthAction::PrevMonth) }
-            >
-                {"arrow_back"}
-            </button>
-            <button
-                title={ translations::NextMonth.static_translate(language) }
-                class="material-symbols-outlined"
-                disabled={
-                    year_month.month == Month::December
-                    && NaiveDate::from_ymd_opt(year_month.year + 1, 1, 1).is_none()
+        }}
+        if !kiosk_mode {
+            <div class="corner-buttons">
+                if show_today_button {
+                    <button
+                        title={ translations::Today.static_translate(language) }
+                        aria-label={ translations::Today.static_translate(language) }
+                        class="material-symbols-outlined"
+                        onclick={ navigate.reform(move |_| YearMonthAction::Today(today)) }
+                    >
+                        {"today"}
+                    </button>
                 }
-                onclick={ move |_| year_month_dispatcher1.dispatch(YearMonthAction::NextMonth) }
-            >
-                {"arrow_forward"}
-            </button>
-            <button
-                title={ translations::Jump.static_translate(language) }
-                class={ classes!(
-                    "material-symbols-outlined",
-                    (active_dialog_value == Some(Dialog::Jump)).then_some("active"),
-                ) }
-                onclick={ move |_| active_dialog_dispatcher.dispatch(Some(Dialog::Jump)) }
-            >
-                {"calendar_month"}
-            </button>
-            <div class={ classes!("more", show_more.0.then_some("visible")) }>
                 <button
-                    title={ translations::Print.static_translate(language) }
+                    title={ translations::PrevMonth.static_translate(language) }
+                    aria-label={ translations::PrevMonth.static_translate(language) }
                     class="material-symbols-outlined"
-                    onclick={ |_| {
-                        if let Some(window) = web_sys::window() {
-                            let _ = window.print();
-                        }
-                    } }
+                    disabled={
+                        year_month.month == Month::January
+                        && NaiveDate::from_ymd_opt(year_month.year - 1, 1, 1).is_none()
+                    }
+                    onclick={ navigate.reform(|_| YearMonthAction::PrevMonth) }
                 >
-                    {"print"}
+                    {"arrow_back"}
                 </button>
                 <button
-                    title={ translations::Styles.static_translate(language) }
-                    class={ classes!(
-                        "material-symbols-outlined",
-                        (active_dialog_value == Some(Dialog::Styles)).then_some("active"),
-                    ) }
-                    onclick={ move |_| active_dialog_dispatcher1.dispatch(Some(Dialog::Styles)) }
+                    title={ translations::NextMonth.static_translate(language) }
+                    aria-label={ translations::NextMonth.static_translate(language) }
+                    class="material-symbols-outlined"
+                    disabled={
+                        year_month.month == Month::December
+                        && NaiveDate::from_ymd_opt(year_month.year + 1, 1, 1).is_none()
+                    }
+                    onclick={ navigate.reform(|_| YearMonthAction::NextMonth) }
                 >
-                    {"style"}
+                    {"arrow_forward"}
                 </button>
+                { dialog_button(Dialog::Jump, &translations::Jump, "calendar_month") }
+                <div class={ classes!("more", show_more.0.then_some("visible")) }>
+                    { dialog_button(Dialog::Print, &translations::PrintSetup, "print") }
+                    { dialog_button(Dialog::Styles, &translations::Styles, "style") }
+                    { dialog_button(Dialog::Settings, &translations::Settings, "settings") }
+                    { dialog_button(Dialog::Shortcuts, &translations::KeyboardShortcuts, "keyboard") }
+                    { dialog_button(Dialog::Export, &translations::Export, "download") }
+                    <a href="https://github.com/supertsy5/calendar">
+                        <button title="GitHub" aria-label="GitHub" class="material-symbols-outlined">{"code"}</button>
+                    </a>
+                </div>
                 <button
-                    title={ translations::Settings.static_translate(language) }
+                    title={ translations::More.static_translate(language) }
+                    aria-label={ translations::More.static_translate(language) }
                     class={ classes!(
                         "material-symbols-outlined",
-                        (active_dialog_value == Some(Dialog::Settings)).then_some("active"),
+                        "button-more",
+                        show_more.0.then_some("active"),
                     ) }
-                    onclick={ move |_| active_dialog_dispatcher2.dispatch(Some(Dialog::Settings)) }
+                    aria-expanded={ if show_more.0 { "true" } else { "false" } }
+                    onclick={ {
+                        let show_more_dispatcher = show_more.dispatcher();
+                        move |_| {
+                            open_dialog.emit(None);
+                            show_more_dispatcher.dispatch(());
+                        }
+                    } }
                 >
-                    {"settings"}
+                    {"more_horiz"}
                 </button>
-                <a href="https://github.com/supertsy5/calendar">
-                    <button title="GitHub" class="material-symbols-outlined">{"code"}</button>
-                </a>
             </div>
-            <button
-                title={ translations::Settings.static_translate(language) }
-                class={ classes!(
-                    "material-symbols-outlined",
-                    "button-more",
-                    show_more.0.then_some("active"),
-                ) }
-                onclick={ move |_| {
-                    active_dialog_dispatcher3.dispatch(None);
-                    show_more_dispatcher.dispatch(());
-                } }
-            >
-                {"more_horiz"}
-            </button>
-        </div>
-    </>}
+        } else {
+            <div class="kiosk-shield" aria-hidden="true" oncontextmenu={ |event: MouseEvent| event.prevent_default() }></div>
+        }
+    </ContextProvider<SettingsContext>> }
 }
@@ -0,0 +1,390 @@
+//! Positions of the sun after Jean Meeus, *Astronomical Algorithms* (2nd ed.),
+//! using the abridged VSOP87 series for the earth.
+
+use std::f64::consts::PI;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use nongli::SolarTerm;
+
+/// Julian day of the Unix epoch.
+const UNIX_EPOCH: f64 = 2440587.5;
+/// Julian day of the standard epoch J2000.0.
+const J2000: f64 = 2451545.0;
+/// Mean length of the tropical year in days.
+const TROPICAL_YEAR: f64 = 365.2422;
+
+const EARTH_L0: &[(f64, f64, f64)] = &[
+    (175347046.0, 0.0, 0.0),
+    (3341656.0, 4.6692568, 6283.07585),
+    (34894.0, 4.6261, 12566.1517),
+    (3497.0, 2.7441, 5753.3849),
+    (3418.0, 2.8289, 3.5231),
+    (3136.0, 3.6277, 77713.7715),
+    (2676.0, 4.4181, 7860.4194),
+    (2343.0, 6.1352, 3930.2097),
+    (1324.0, 0.7425, 11506.7698),
+    (1273.0, 2.0371, 529.691),
+    (1199.0, 1.1096, 1577.3435),
+    (990.0, 5.233, 5884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5223.694),
+    (753.0, 2.533, 5507.553),
+    (505.0, 4.583, 18849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.92, 0.067),
+    (317.0, 5.849, 11790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10977.079),
+    (243.0, 0.345, 5486.778),
+    (206.0, 4.806, 2544.314),
+    (205.0, 1.869, 5573.143),
+    (202.0, 2.458, 6069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.98),
+    (103.0, 0.636, 4694.003),
+    (102.0, 0.976, 15720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161000.69),
+    (85.0, 1.3, 6275.96),
+    (85.0, 3.67, 71430.7),
+    (80.0, 1.81, 17260.15),
+    (79.0, 3.04, 12036.46),
+    (75.0, 1.76, 5088.63),
+    (74.0, 3.5, 3154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9437.76),
+    (62.0, 3.98, 8827.39),
+    (61.0, 1.82, 7084.9),
+    (57.0, 2.78, 6286.6),
+    (56.0, 4.39, 14143.5),
+    (56.0, 3.47, 6279.55),
+    (52.0, 0.19, 12139.55),
+    (52.0, 1.33, 1748.02),
+    (51.0, 0.28, 5856.48),
+    (49.0, 0.49, 1194.45),
+    (41.0, 5.37, 8429.24),
+    (41.0, 2.4, 19651.05),
+    (39.0, 6.17, 10447.39),
+    (37.0, 6.04, 10213.29),
+    (37.0, 2.57, 1059.38),
+    (36.0, 1.71, 2352.87),
+    (36.0, 1.78, 6812.77),
+    (33.0, 0.59, 17789.85),
+    (30.0, 0.44, 83996.85),
+    (30.0, 2.74, 1349.87),
+    (25.0, 3.16, 4690.48),
+];
+
+const EARTH_L1: &[(f64, f64, f64)] = &[
+    (628331966747.0, 0.0, 0.0),
+    (206059.0, 2.678235, 6283.07585),
+    (4303.0, 2.6351, 12566.1517),
+    (425.0, 1.59, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1577.344),
+    (93.0, 2.59, 18849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5507.55),
+    (59.0, 2.89, 5223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.4, 796.3),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5486.78),
+    (19.0, 4.97, 213.3),
+    (17.0, 2.99, 6275.96),
+    (16.0, 0.03, 2544.31),
+    (16.0, 1.43, 2146.17),
+    (15.0, 1.21, 10977.08),
+    (12.0, 2.83, 1748.02),
+    (12.0, 3.26, 5088.63),
+    (12.0, 5.27, 1194.45),
+    (12.0, 2.08, 4694.0),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.3, 6286.6),
+    (10.0, 4.24, 1349.87),
+    (9.0, 2.7, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.3, 2352.87),
+    (6.0, 2.65, 9437.76),
+    (6.0, 4.67, 4690.48),
+];
+
+const EARTH_L2: &[(f64, f64, f64)] = &[
+    (52919.0, 0.0, 0.0),
+    (8720.0, 1.0721, 6283.0758),
+    (309.0, 0.867, 12566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.3),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18849.23),
+    (9.0, 2.06, 77713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5573.14),
+    (3.0, 5.14, 796.3),
+    (3.0, 6.05, 5507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const EARTH_L3: &[(f64, f64, f64)] = &[
+    (289.0, 5.844, 6283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12566.15),
+    (3.0, 5.2, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.3, 18849.23),
+    (1.0, 5.97, 242.73),
+];
+
+const EARTH_L4: &[(f64, f64, f64)] = &[
+    (114.0, PI, 0.0),
+    (8.0, 4.13, 6283.08),
+    (1.0, 3.84, 12566.15),
+];
+
+const EARTH_L5: &[(f64, f64, f64)] = &[(1.0, PI, 0.0)];
+
+fn series(terms: &[(f64, f64, f64)], tau: f64) -> f64 {
+    terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum()
+}
+
+pub fn julian_day(datetime: DateTime<Utc>) -> f64 {
+    UNIX_EPOCH + datetime.timestamp_millis() as f64 / 86_400_000.0
+}
+
+pub fn from_julian_day(julian_day: f64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(((julian_day - UNIX_EPOCH) * 86_400_000.0).round() as i64)
+}
+
+/// Difference between terrestrial time and universal time in seconds,
+/// from the polynomial expressions of Espenak and Meeus.
+pub fn delta_t(year: f64) -> f64 {
+    let long_term = |year: f64| {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    match year {
+        ..1900.0 => long_term(year),
+        ..1920.0 => {
+            let t = year - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        ..1941.0 => {
+            let t = year - 1920.0;
+            21.20 + 0.84493 * t - 0.0761 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        ..1961.0 => {
+            let t = year - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        ..1986.0 => {
+            let t = year - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        ..2005.0 => {
+            let t = year - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        ..2050.0 => {
+            let t = year - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        ..2150.0 => long_term(year) - 0.5628 * (2150.0 - year),
+        _ => long_term(year),
+    }
+}
+
+/// Nutation in longitude in degrees, `t` being Julian centuries from J2000.0.
+fn nutation_in_longitude(t: f64) -> f64 {
+    let omega = (125.04452 - 1934.136261 * t).to_radians();
+    let sun = (280.4665 + 36000.7698 * t).to_radians();
+    let moon = (218.3165 + 481267.8813 * t).to_radians();
+    (-17.20 * omega.sin() - 1.32 * (2.0 * sun).sin() - 0.23 * (2.0 * moon).sin()
+        + 0.21 * (2.0 * omega).sin())
+        / 3600.0
+}
+
+/// Apparent geocentric longitude of the sun in degrees at Julian ephemeris day `jde`.
+pub fn sun_apparent_longitude(jde: f64) -> f64 {
+    let tau = (jde - J2000) / 365_250.0;
+    let earth = [EARTH_L5, EARTH_L4, EARTH_L3, EARTH_L2, EARTH_L1, EARTH_L0]
+        .iter()
+        .fold(0.0, |acc, terms| acc * tau + series(terms, tau))
+        / 1e8;
+    let geometric = earth.to_degrees() + 180.0;
+    let fk5 = -0.09033 / 3600.0;
+    let aberration = -20.4898 / 3600.0;
+    (geometric + fk5 + nutation_in_longitude(tau * 10.0) + aberration).rem_euclid(360.0)
+}
+
+/// Celestial longitude of the sun in degrees at which `solar_term` begins.
+pub fn solar_term_longitude(solar_term: SolarTerm) -> f64 {
+    (285.0 + 15.0 * solar_term.as_ordinal() as f64) % 360.0
+}
+
+/// The instant when the sun reaches the longitude of `solar_term` in `year`.
+pub fn solar_term_instant(year: i32, solar_term: SolarTerm) -> Option<DateTime<Utc>> {
+    let ordinal = solar_term.as_ordinal() as f64;
+    let target = solar_term_longitude(solar_term);
+    let new_year = NaiveDate::from_ymd_opt(year, 1, 1)?.and_hms_opt(0, 0, 0)?;
+    let mut jde = julian_day(new_year.and_utc()) + 5.0 + ordinal * TROPICAL_YEAR / 24.0;
+    for _ in 0..10 {
+        let difference = (target - sun_apparent_longitude(jde) + 540.0).rem_euclid(360.0) - 180.0;
+        jde += difference * TROPICAL_YEAR / 360.0;
+        if difference.abs() < 1e-8 {
+            break;
+        }
+    }
+    from_julian_day(jde - delta_t(year as f64 + (ordinal + 0.5) / 24.0) / 86_400.0)
+}
+
+/// The instant of the new moon of lunation `k`, counted from the new moon of 2000-01-06.
+pub fn new_moon(k: i32) -> Option<DateTime<Utc>> {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let jde = 2451550.09766 + 29.530588861 * k + 0.00015437 * t.powi(2) - 0.00000015 * t.powi(3)
+        + 0.00000000073 * t.powi(4);
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t.powi(2);
+    let sun_anomaly =
+        (2.5534 + 29.1053567 * k - 0.0000014 * t.powi(2) - 0.00000011 * t.powi(3)).to_radians();
+    let moon_anomaly =
+        (201.5643 + 385.81693528 * k + 0.0107582 * t.powi(2) + 0.00001238 * t.powi(3)
+            - 0.000000058 * t.powi(4))
+        .to_radians();
+    let latitude = (160.7108 + 390.67050284 * k - 0.0016118 * t.powi(2) - 0.00000227 * t.powi(3)
+        + 0.000000011 * t.powi(4))
+    .to_radians();
+    let node =
+        (124.7746 - 1.56375588 * k + 0.0020672 * t.powi(2) + 0.00000215 * t.powi(3)).to_radians();
+    let (m, mp, f) = (sun_anomaly, moon_anomaly, latitude);
+    let correction = -0.4072 * mp.sin()
+        + 0.17241 * e * m.sin()
+        + 0.01608 * (2.0 * mp).sin()
+        + 0.01039 * (2.0 * f).sin()
+        + 0.00739 * e * (mp - m).sin()
+        - 0.00514 * e * (mp + m).sin()
+        + 0.00208 * e * e * (2.0 * m).sin()
+        - 0.00111 * (mp - 2.0 * f).sin()
+        - 0.00057 * (mp + 2.0 * f).sin()
+        + 0.00056 * e * (2.0 * mp + m).sin()
+        - 0.00042 * (3.0 * mp).sin()
+        + 0.00042 * e * (m + 2.0 * f).sin()
+        + 0.00038 * e * (m - 2.0 * f).sin()
+        - 0.00024 * e * (2.0 * mp - m).sin()
+        - 0.00017 * node.sin()
+        - 0.00007 * (mp + 2.0 * m).sin()
+        + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+        + 0.00004 * (3.0 * m).sin()
+        + 0.00003 * (mp + m - 2.0 * f).sin()
+        + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+        - 0.00003 * (mp + m + 2.0 * f).sin()
+        + 0.00003 * (mp - m + 2.0 * f).sin()
+        - 0.00002 * (mp - m - 2.0 * f).sin()
+        - 0.00002 * (3.0 * mp + m).sin()
+        + 0.00002 * (4.0 * mp).sin();
+    let planetary = [
+        (0.000325, 299.77 + 0.107408 * k - 0.009173 * t.powi(2)),
+        (0.000165, 251.88 + 0.016321 * k),
+        (0.000164, 251.83 + 26.651886 * k),
+        (0.000126, 349.42 + 36.412478 * k),
+        (0.00011, 84.66 + 18.206239 * k),
+        (0.000062, 141.74 + 53.303771 * k),
+        (0.00006, 207.14 + 2.453732 * k),
+        (0.000056, 154.84 + 7.30686 * k),
+        (0.000047, 34.52 + 27.261239 * k),
+        (0.000042, 207.19 + 0.121824 * k),
+        (0.00004, 291.34 + 1.844379 * k),
+        (0.000037, 161.72 + 24.198154 * k),
+        (0.000035, 239.56 + 25.513099 * k),
+        (0.000023, 331.55 + 3.592518 * k),
+    ]
+    .iter()
+    .map(|(coefficient, angle)| coefficient * angle.to_radians().sin())
+    .sum::<f64>();
+    let jde = jde + correction + planetary;
+    from_julian_day(jde - delta_t(2000.0 + (jde - J2000) / 365.25) / 86_400.0)
+}
+
+/// New moons happening within `start..end`.
+pub fn new_moons(start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let first = ((julian_day(start) - 2451550.09766) / 29.530588861).floor() as i32 - 1;
+    (first..)
+        .map_while(new_moon)
+        .skip_while(|instant| *instant < start)
+        .take_while(|instant| *instant < end)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    /// Whether `instant` is within a minute of `expected`, which is rounded to the minute.
+    fn assert_near(instant: Option<DateTime<Utc>>, expected: DateTime<Utc>) {
+        let instant = instant.unwrap();
+        assert!(
+            (instant - expected).num_seconds().abs() <= 60,
+            "{instant} is not {expected}",
+        );
+    }
+
+    #[test]
+    fn finds_solar_terms() {
+        assert_near(
+            solar_term_instant(2025, SolarTerm::Lichun),
+            utc(2025, 2, 3, 14, 10),
+        );
+        assert_eq!(solar_term_longitude(SolarTerm::Lichun), 315.0);
+    }
+
+    #[test]
+    fn finds_solar_terms_around_the_new_year() {
+        // The December solstice is the last term of a year, right before the first of the next.
+        assert_near(
+            solar_term_instant(2024, SolarTerm::Dongzhi),
+            utc(2024, 12, 21, 9, 20),
+        );
+        assert_near(
+            solar_term_instant(2025, SolarTerm::Xiaohan),
+            utc(2025, 1, 5, 2, 32),
+        );
+        assert_near(
+            solar_term_instant(2025, SolarTerm::Dongzhi),
+            utc(2025, 12, 21, 15, 3),
+        );
+        assert_eq!(solar_term_longitude(SolarTerm::Dongzhi), 270.0);
+    }
+
+    #[test]
+    fn finds_new_moons() {
+        let found = new_moons(utc(2024, 12, 15, 0, 0), utc(2025, 2, 15, 0, 0));
+        assert_eq!(found.len(), 2);
+        assert_near(found.first().copied(), utc(2024, 12, 30, 22, 27));
+        assert_near(found.get(1).copied(), utc(2025, 1, 29, 12, 36));
+    }
+}
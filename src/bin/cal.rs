@@ -0,0 +1,155 @@
+//! Prints calendars to the terminal, like `cal`.
+
+use std::{io::IsTerminal, process::ExitCode};
+
+use calendar::{
+    export::text,
+    model::{self, MonthPage},
+    time_zone::DisplayZone,
+};
+use chrono::{Datelike, Month, NaiveDate};
+use clap::{arg, value_parser, ArgMatches, Command};
+use nongli::{calendar::Options, language::Language};
+
+fn cmd() -> Command {
+    Command::new("cal")
+        .about("Prints a month or a year of the calendar, with the Chinese calendar")
+        .arg(
+            arg!([month] "Month (1-12), or the year if no year follows")
+                .value_parser(value_parser!(i32)),
+        )
+        .arg(arg!([year] "Year").value_parser(value_parser!(i32).range(-262143..=262142)))
+        .arg(arg!(-y --year "Print the whole year").id("whole-year"))
+        .arg(arg!(-'3' --three "Print the previous, current and next month"))
+        .arg(arg!(-l --landscape "Print the year in 3 rows and 4 columns"))
+        .arg(arg!(-M --monday "Start weeks on Monday"))
+        .arg(arg!(-w --"week-numbers" "Print week numbers"))
+        .arg(arg!(-n --"no-highlight-today" "Do not highlight today"))
+        .arg(
+            arg!(-c --chinese <when> "Print the Chinese calendar")
+                .value_parser(["always", "auto", "never"])
+                .default_value("auto"),
+        )
+        .arg(
+            arg!(-L --language <language> "Language, defaults to the one of LANG")
+                .value_parser(["en", "zh-Hans", "zh-Hant"]),
+        )
+        .arg(
+            arg!(--color <when> "Color weekends, festivals, solar terms and today")
+                .value_parser(["always", "auto", "never"])
+                .default_value("auto"),
+        )
+}
+
+/// The language of a locale such as `zh_TW.UTF-8`.
+fn locale_language(locale: &str) -> Language {
+    let locale = locale.split('.').next().unwrap_or_default();
+    match locale.split_once('_') {
+        Some(("zh", region)) if ["HK", "MO", "TW"].contains(&region) => {
+            Language::ChineseTraditional
+        }
+        Some(("zh", _)) => Language::ChineseSimplified,
+        None if locale == "zh" => Language::ChineseSimplified,
+        _ => Language::English,
+    }
+}
+
+fn year_month(date: NaiveDate) -> (i32, Month) {
+    (date.year(), Month::try_from(date.month() as u8).unwrap())
+}
+
+fn when(matches: &ArgMatches, id: &str, auto: bool) -> bool {
+    match matches.get_one::<String>(id).map(String::as_str) {
+        Some("always") => true,
+        Some("never") => false,
+        _ => auto,
+    }
+}
+
+fn main() -> ExitCode {
+    let matches = cmd().get_matches();
+
+    let language = match matches.get_one::<String>("language").map(String::as_str) {
+        Some("zh-Hans") => Language::ChineseSimplified,
+        Some("zh-Hant") => Language::ChineseTraditional,
+        Some(_) => Language::English,
+        None => std::env::var("LANG")
+            .map(|locale| locale_language(&locale))
+            .unwrap_or(Language::English),
+    };
+    let options = Options {
+        language,
+        enable_chinese: when(&matches, "chinese", language != Language::English),
+        start_on_monday: matches.get_flag("monday"),
+        color: when(&matches, "color", std::io::stdout().is_terminal()),
+        week_number: matches.get_flag("week-numbers"),
+    };
+    let zone = DisplayZone(None);
+    let today = zone.today();
+    let highlight_today = !matches.get_flag("no-highlight-today");
+
+    // `cal 2025` prints a year, `cal 3 2025` a month, and `cal` the current month.
+    let (month, year) = match (
+        matches.get_one::<i32>("month").copied(),
+        matches.get_one::<i32>("year").copied(),
+    ) {
+        (Some(month), Some(year)) => (Some(month), year),
+        (Some(year), None) => (None, year),
+        _ => (Some(today.month() as i32), today.year()),
+    };
+    let month = if matches.get_flag("whole-year") {
+        None
+    } else {
+        match month.map(|month| {
+            u8::try_from(month)
+                .ok()
+                .and_then(|month| Month::try_from(month).ok())
+        }) {
+            Some(Some(month)) => Some(month),
+            Some(None) => {
+                eprintln!("cal: month must be from 1 to 12");
+                return ExitCode::FAILURE;
+            }
+            None => None,
+        }
+    };
+
+    let (first, last) = match month {
+        Some(month) if matches.get_flag("three") => {
+            NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).map_or(
+                ((year, month), (year, month)),
+                |date| {
+                    let months = chrono::Months::new(1);
+                    (
+                        year_month(date.checked_sub_months(months).unwrap_or(date)),
+                        year_month(date.checked_add_months(months).unwrap_or(date)),
+                    )
+                },
+            )
+        }
+        Some(month) => ((year, month), (year, month)),
+        None => ((year, Month::January), (year, Month::December)),
+    };
+    let pages = model::month_range(first, last)
+        .into_iter()
+        .filter_map(|(year, month)| {
+            MonthPage::new(year, month, today, highlight_today, options, zone)
+        })
+        .collect::<Vec<_>>();
+    let printed = (!pages.is_empty()).then(|| match month {
+        Some(_) => print!("{}", text::months(&pages, true)),
+        None => print!(
+            "{}",
+            text::year(
+                year,
+                &pages,
+                if matches.get_flag("landscape") { 4 } else { 3 }
+            )
+        ),
+    });
+    if printed.is_none() {
+        eprintln!("cal: date out of range");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,132 @@
+//! Renders months ahead of time into pages, which the app takes over when they load.
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use calendar::{
+    app::{App, Props},
+    model, prerender, translations,
+};
+use chrono::Month;
+use clap::{arg, value_parser, Command};
+use nongli::language::{MonthTitle, Translate};
+use yew::ServerRenderer;
+
+fn cmd() -> Command {
+    Command::new("prerender")
+        .about("Renders a range of months into HTML pages, one file for each month")
+        .arg(
+            arg!(-t --template <file> "The page built by trunk, such as dist/index.html")
+                .value_parser(value_parser!(PathBuf))
+                .required(true),
+        )
+        .arg(
+            arg!(-o --out <dir> "Directory to write YYYY-MM.html files into")
+                .value_parser(value_parser!(PathBuf))
+                .default_value("."),
+        )
+        .arg(arg!(--from <month> "First month, such as 2025-01").required(true))
+        .arg(arg!(--to [month] "Last month, the first one if not given"))
+        .arg(
+            arg!(-L --language <language> "Language")
+                .value_parser(["en", "zh-Hans", "zh-Hant"])
+                .default_value("en"),
+        )
+        .arg(arg!(-c --chinese "Show the Chinese calendar"))
+        .arg(arg!(-M --monday "Start weeks on Monday"))
+        .arg(arg!(-w --"week-numbers" "Show week numbers"))
+        .arg(arg!(-n --"no-highlight-today" "Do not highlight the day the pages are made"))
+}
+
+fn parse_month(month: &str) -> Option<(i32, Month)> {
+    let (year, month) = month.rsplit_once('-')?;
+    Some((
+        year.parse().ok()?,
+        Month::try_from(month.parse::<u8>().ok()?).ok()?,
+    ))
+}
+
+/// Puts the rendered app into the body of the template, its title into the title,
+/// and its language into the `lang` attribute.
+fn fill(template: &str, title: &str, language: &str, root: &str) -> Option<String> {
+    let body = template.find("<body")?;
+    let body = body + template[body..].find('>')? + 1;
+    let mut page = format!("{}\n{root}{}", &template[..body], &template[body..]);
+    if let (Some(start), Some(end)) = (page.find("<title>"), page.find("</title>")) {
+        page.replace_range(start + "<title>".len()..end, title);
+    }
+    Some(page.replacen("<html>", &format!("<html lang=\"{language}\">"), 1))
+}
+
+fn main() -> ExitCode {
+    let matches = cmd().get_matches();
+    let template = matches.get_one::<PathBuf>("template").unwrap();
+    let template = match fs::read_to_string(template) {
+        Ok(template) => template,
+        Err(error) => {
+            eprintln!("prerender: {}: {error}", template.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let out = matches.get_one::<PathBuf>("out").unwrap();
+    let from = matches.get_one::<String>("from").unwrap();
+    let Some(from) = parse_month(from) else {
+        eprintln!("prerender: {from} is not a month such as 2025-01");
+        return ExitCode::FAILURE;
+    };
+    let to = match matches.get_one::<String>("to") {
+        Some(to) => match parse_month(to) {
+            Some(to) => to,
+            None => {
+                eprintln!("prerender: {to} is not a month such as 2025-01");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => from,
+    };
+    let settings = Props {
+        month: None,
+        language: matches
+            .get_one::<String>("language")
+            .and_then(|tag| translations::language_from_tag(tag))
+            .unwrap_or(Props::default().language),
+        enable_chinese: matches.get_flag("chinese"),
+        start_on_monday: matches.get_flag("monday"),
+        show_week_numbers: matches.get_flag("week-numbers"),
+        highlight_today: !matches.get_flag("no-highlight-today"),
+    };
+    if let Err(error) = fs::create_dir_all(out) {
+        eprintln!("prerender: {}: {error}", out.display());
+        return ExitCode::FAILURE;
+    }
+
+    for (year, month) in model::month_range(from, to) {
+        let props = Props {
+            month: Some((year, month)),
+            ..settings
+        };
+        let app = futures::executor::block_on(
+            ServerRenderer::<App>::with_props(move || props)
+                .hydratable(true)
+                .render(),
+        );
+        let root = format!("<div {}>{app}</div>\n", prerender::attributes(&props));
+        let title = MonthTitle {
+            year,
+            month,
+            enable_chinese: props.enable_chinese,
+        }
+        .translate_to_string(props.language);
+        let language = translations::language_tag(props.language);
+        let Some(page) = fill(&template, &title, language, &root) else {
+            eprintln!("prerender: the template has no <body>");
+            return ExitCode::FAILURE;
+        };
+        let path = out.join(format!("{year}-{:02}.html", month.number_from_month()));
+        if let Err(error) = fs::write(&path, page) {
+            eprintln!("prerender: {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("{}", path.display());
+    }
+    ExitCode::SUCCESS
+}
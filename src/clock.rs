@@ -0,0 +1,26 @@
+use gloo_timers::callback::Interval;
+use yew::prelude::*;
+
+use crate::time_zone::DisplayZone;
+
+#[derive(Clone, Copy, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub time_zone: DisplayZone,
+}
+
+#[function_component]
+pub fn Clock(props: &Props) -> Html {
+    let now = use_state(|| props.time_zone.now());
+    {
+        let now = now.clone();
+        use_effect_with(props.time_zone, move |time_zone| {
+            let time_zone = *time_zone;
+            now.set(time_zone.now());
+            let interval = Interval::new(1000, move || now.set(time_zone.now()));
+            move || drop(interval)
+        });
+    }
+    html! {
+        <span class="clock">{ now.format("%H:%M:%S").to_string() }</span>
+    }
+}
@@ -0,0 +1,434 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Keywords taking the color from elsewhere, which are valid colors without a value of their own.
+pub const KEYWORDS: &[&str] = &["inherit", "currentColor"];
+
+/// The named colors of CSS, in alphabetical order.
+const NAMED: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+/// A color with components from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgba {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+impl Rgba {
+    pub const BLACK: Self = Self {
+        red: 0.0,
+        green: 0.0,
+        blue: 0.0,
+        alpha: 1.0,
+    };
+
+    pub const WHITE: Self = Self {
+        red: 1.0,
+        green: 1.0,
+        blue: 1.0,
+        alpha: 1.0,
+    };
+
+    /// Parses hexadecimal colors, named colors, `transparent`,
+    /// and the `rgb()`, `rgba()`, `hsl()` and `hsla()` notations.
+    pub fn parse(color: &str) -> Option<Self> {
+        let color = color.trim().to_ascii_lowercase();
+        if let Some(hex) = color.strip_prefix('#') {
+            return Self::parse_hex(hex);
+        }
+        if color == "transparent" {
+            return Some(Self {
+                alpha: 0.0,
+                ..Self::BLACK
+            });
+        }
+        if let Ok(i) = NAMED.binary_search_by_key(&color.as_str(), |(name, _)| name) {
+            return Self::parse_hex(&format!("{:06x}", NAMED[i].1));
+        }
+        let (function, arguments) = color.strip_suffix(')')?.split_once('(')?;
+        let mut components = arguments
+            .split(|ch: char| ch == ',' || ch == '/' || ch.is_whitespace())
+            .filter(|component| !component.is_empty());
+        let (red, green, blue) = match function.trim() {
+            "rgb" | "rgba" => {
+                let mut channel = || {
+                    let component = components.next()?;
+                    match component.strip_suffix('%') {
+                        Some(percent) => percent.parse::<f32>().ok().map(|value| value / 100.0),
+                        None => component.parse::<f32>().ok().map(|value| value / 255.0),
+                    }
+                };
+                (channel()?, channel()?, channel()?)
+            }
+            "hsl" | "hsla" => {
+                let hue = components.next()?;
+                let hue = hue.strip_suffix("deg").unwrap_or(hue).parse::<f32>().ok()?;
+                let mut percent = || {
+                    let component = components.next()?;
+                    component
+                        .strip_suffix('%')
+                        .unwrap_or(component)
+                        .parse::<f32>()
+                        .ok()
+                        .map(|value| (value / 100.0).clamp(0.0, 1.0))
+                };
+                let (saturation, lightness) = (percent()?, percent()?);
+                hsl_to_rgb(hue, saturation, lightness)
+            }
+            _ => return None,
+        };
+        let alpha = match components.next() {
+            Some(component) => match component.strip_suffix('%') {
+                Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+                None => component.parse::<f32>().ok()?,
+            },
+            None => 1.0,
+        };
+        if components.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            red: red.clamp(0.0, 1.0),
+            green: green.clamp(0.0, 1.0),
+            blue: blue.clamp(0.0, 1.0),
+            alpha: alpha.clamp(0.0, 1.0),
+        })
+    }
+
+    /// The color seen when it is drawn over an opaque `background`.
+    pub fn over(self, background: Self) -> Self {
+        let mix = |front: f32, back: f32| front * self.alpha + back * (1.0 - self.alpha);
+        Self {
+            red: mix(self.red, background.red),
+            green: mix(self.green, background.green),
+            blue: mix(self.blue, background.blue),
+            alpha: 1.0,
+        }
+    }
+
+    /// The color `amount` of the way towards `other`.
+    pub fn mix(self, other: Self, amount: f32) -> Self {
+        let mix = |from: f32, to: f32| from + (to - from) * amount;
+        Self {
+            red: mix(self.red, other.red),
+            green: mix(self.green, other.green),
+            blue: mix(self.blue, other.blue),
+            alpha: mix(self.alpha, other.alpha),
+        }
+    }
+
+    /// Relative luminance as defined by WCAG, ignoring the alpha.
+    pub fn luminance(self) -> f32 {
+        let linear = |value: f32| {
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// WCAG contrast ratio between two opaque colors, from 1 to 21.
+    pub fn contrast(self, other: Self) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// The color without transparency, as `#rrggbb`.
+    pub fn to_hex(self) -> String {
+        let byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            byte(self.red),
+            byte(self.green),
+            byte(self.blue)
+        )
+    }
+
+    fn parse_hex(hex: &str) -> Option<Self> {
+        if !hex.is_ascii() {
+            return None;
+        }
+        let digits = match hex.len() {
+            3 | 4 => hex
+                .chars()
+                .map(|digit| digit.to_digit(16).map(|value| value * 17))
+                .collect::<Option<Vec<_>>>()?,
+            6 | 8 => (0..hex.len())
+                .step_by(2)
+                .map(|i| u32::from_str_radix(&hex[i..i + 2], 16).ok())
+                .collect::<Option<Vec<_>>>()?,
+            _ => return None,
+        };
+        let channel = |i: usize| digits.get(i).map_or(1.0, |value| *value as f32 / 255.0);
+        Some(Self {
+            red: channel(0),
+            green: channel(1),
+            blue: channel(2),
+            alpha: channel(3),
+        })
+    }
+}
+
+/// The shortest hexadecimal notation, with the alpha only if the color is transparent.
+impl Display for Rgba {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(&self.to_hex())?;
+        if self.alpha < 1.0 {
+            write!(
+                f,
+                "{:02x}",
+                (self.alpha.clamp(0.0, 1.0) * 255.0).round() as u8
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (f32, f32, f32) {
+    let channel = |n: f32| {
+        let k = (n + hue.rem_euclid(360.0) / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (channel(0.0), channel(8.0), channel(4.0))
+}
+
+/// Whether the color is one of [`KEYWORDS`].
+pub fn is_keyword(color: &str) -> bool {
+    KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(color.trim()))
+}
+
+/// Whether the browser takes the value as a color, which covers notations [`Rgba::parse`] does not know.
+pub fn is_valid(color: &str) -> bool {
+    if is_keyword(color) || Rgba::parse(color).is_some() {
+        return true;
+    }
+    cfg!(target_arch = "wasm32")
+        && web_sys::css::supports_with_value("color", color).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(color: &str) -> Option<String> {
+        Rgba::parse(color).map(|rgba| rgba.to_string())
+    }
+
+    #[test]
+    fn parses_hexadecimal_colors() {
+        assert_eq!(hex("#f80"), Some("#ff8800".to_string()));
+        assert_eq!(hex("#f808"), Some("#ff880088".to_string()));
+        assert_eq!(hex("#FF8800"), Some("#ff8800".to_string()));
+        assert_eq!(hex("#ff880080"), Some("#ff880080".to_string()));
+        assert_eq!(Rgba::parse("#ff880080").unwrap().alpha, 128.0 / 255.0);
+    }
+
+    #[test]
+    fn parses_named_colors() {
+        assert!(NAMED.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(hex("RebeccaPurple"), Some("#663399".to_string()));
+        assert_eq!(hex(" white "), Some("#ffffff".to_string()));
+        assert_eq!(Rgba::parse("transparent").unwrap().alpha, 0.0);
+    }
+
+    #[test]
+    fn parses_functions() {
+        assert_eq!(hex("rgb(255, 136, 0)"), Some("#ff8800".to_string()));
+        assert_eq!(hex("rgb(255 136 0)"), Some("#ff8800".to_string()));
+        assert_eq!(hex("rgb(100% 0% 0%)"), Some("#ff0000".to_string()));
+        assert_eq!(hex("rgba(255, 0, 0, 0.5)"), Some("#ff000080".to_string()));
+        assert_eq!(hex("rgb(255 0 0 / 50%)"), Some("#ff000080".to_string()));
+        assert_eq!(hex("hsl(120, 100%, 50%)"), Some("#00ff00".to_string()));
+        assert_eq!(hex("hsl(240deg 100% 25%)"), Some("#000080".to_string()));
+        assert_eq!(
+            hex("hsla(0, 0%, 100%, 0.25)"),
+            Some("#ffffff40".to_string())
+        );
+        assert_eq!(hex("hsl(-120 100% 50% / 1)"), Some("#0000ff".to_string()));
+    }
+
+    #[test]
+    fn rejects_typos() {
+        for typo in [
+            "",
+            "#",
+            "#12",
+            "#12345",
+            "#ggg",
+            "#ff88001",
+            "whit",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(1 2 3",
+            "rgp(1 2 3)",
+            "rgb(1 2 x)",
+            "hsl(a 50% 50%)",
+            "rgb(1 2 3 / x)",
+        ] {
+            assert_eq!(Rgba::parse(typo), None, "{typo}");
+        }
+    }
+
+    #[test]
+    fn measures_contrast() {
+        assert!((Rgba::BLACK.contrast(Rgba::WHITE) - 21.0).abs() < 1e-3);
+        assert!((Rgba::WHITE.contrast(Rgba::WHITE) - 1.0).abs() < 1e-3);
+        let half = Rgba::parse("#00000080").unwrap().over(Rgba::WHITE);
+        assert_eq!(half.to_hex(), "#7f7f7f");
+    }
+}
@@ -0,0 +1,195 @@
+//! Contrast of the colors of the grid against what they are drawn on, following WCAG.
+
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    rc::Rc,
+};
+
+use nongli::language::{Language, StaticTranslate, Translate};
+
+use crate::{
+    color::{self, Rgba},
+    palette::{ColorRole, Palette},
+    translations,
+};
+
+/// Contrast needed by text of normal size.
+const MINIMUM: f32 = 4.5;
+/// Contrast needed by large text, such as the year and the month.
+const LARGE_MINIMUM: f32 = 3.0;
+/// Steps from a color to black or white when looking for one with enough contrast.
+const FIX_STEPS: u8 = 50;
+
+/// Text of one color drawn on another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub foreground: ColorRole,
+    /// The color of the cell, or the white page if `None`.
+    pub background: Option<ColorRole>,
+    large: bool,
+}
+
+impl Pair {
+    const fn new(foreground: ColorRole, background: Option<ColorRole>, large: bool) -> Self {
+        Self {
+            foreground,
+            background,
+            large,
+        }
+    }
+
+    pub fn minimum(self) -> f32 {
+        if self.large {
+            LARGE_MINIMUM
+        } else {
+            MINIMUM
+        }
+    }
+}
+
+/// Every pair of colors the grid draws, where a color which is a keyword is left to its parent's pair.
+pub const PAIRS: [Pair; 10] = [
+    Pair::new(ColorRole::Text, None, false),
+    Pair::new(ColorRole::Year, None, true),
+    Pair::new(ColorRole::Month, None, true),
+    Pair::new(ColorRole::Weekend, None, false),
+    Pair::new(ColorRole::WeekNumber, None, false),
+    Pair::new(ColorRole::Festival, None, false),
+    Pair::new(ColorRole::SolarTerm, None, false),
+    Pair::new(ColorRole::TodayText, Some(ColorRole::Theme), false),
+    Pair::new(ColorRole::Festival, Some(ColorRole::Theme), false),
+    Pair::new(ColorRole::SolarTerm, Some(ColorRole::Theme), false),
+];
+
+/// A pair whose contrast is too low, with a foreground color which would be enough.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    pub pair: Pair,
+    pub ratio: f32,
+    pub fix: Option<Rc<str>>,
+}
+
+impl Translate for Warning {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let foreground = self.pair.foreground.static_translate(language);
+        let background = match self.pair.background {
+            Some(role) => role.static_translate(language),
+            None => translations::PageBackground.static_translate(language),
+        };
+        let (ratio, minimum) = (self.ratio, self.pair.minimum());
+        match language {
+            Language::English => write!(
+                f,
+                "{foreground} on {background}: contrast {ratio:.1}:1, at least {minimum}:1 is needed",
+            ),
+            Language::ChineseSimplified => write!(
+                f,
+                "{background}上的{foreground}：对比度 {ratio:.1}:1，至少需要 {minimum}:1",
+            ),
+            Language::ChineseTraditional => write!(
+                f,
+                "{background}上的{foreground}：對比度 {ratio:.1}:1，至少需要 {minimum}:1",
+            ),
+        }
+    }
+}
+
+fn opaque(palette: &Palette, role: Option<ColorRole>) -> Option<Rgba> {
+    match role {
+        Some(role) => Some(Rgba::parse(&palette[role])?.over(Rgba::WHITE)),
+        None => Some(Rgba::WHITE),
+    }
+}
+
+/// The pairs of the palette with too low a contrast.
+///
+/// Colors which cannot be read, such as ones the browser alone understands, are not checked.
+pub fn check(palette: &Palette) -> Vec<Warning> {
+    PAIRS
+        .into_iter()
+        .filter(|pair| !color::is_keyword(&palette[pair.foreground]))
+        .filter_map(|pair| {
+            let background = opaque(palette, pair.background)?;
+            let foreground = Rgba::parse(&palette[pair.foreground])?;
+            let ratio = foreground.over(background).contrast(background);
+            (ratio < pair.minimum()).then(|| Warning {
+                pair,
+                ratio,
+                fix: fix(foreground, background, pair.minimum()).map(|fix| fix.to_string().into()),
+            })
+        })
+        .collect()
+}
+
+/// The color closest to `foreground` which is darkened or lightened enough to stand out on `background`.
+fn fix(foreground: Rgba, background: Rgba, minimum: f32) -> Option<Rgba> {
+    let foreground = foreground.over(background);
+    [Rgba::BLACK, Rgba::WHITE]
+        .into_iter()
+        .filter_map(|extreme| {
+            (1..=FIX_STEPS)
+                .map(|step| step as f32 / FIX_STEPS as f32)
+                .map(|amount| (amount, foreground.mix(extreme, amount)))
+                // Measured as written out, in whole bytes, which may be a little less.
+                .map(|(amount, color)| (amount, Rgba::parse(&color.to_hex()).unwrap_or(color)))
+                .find(|(_, color)| color.contrast(background) >= minimum)
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, color)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn palette(colors: &[(ColorRole, &str)]) -> Palette {
+        let mut palette = Palette::default();
+        for (role, color) in colors {
+            palette[*role] = Rc::from(*color);
+        }
+        palette
+    }
+
+    #[test]
+    fn warns_about_low_contrast() {
+        let warned = check(&Palette::default())
+            .into_iter()
+            .map(|warning| warning.pair.foreground)
+            .collect::<Vec<_>>();
+        // Gray is enough for the large year and month, but not for week numbers.
+        assert_eq!(warned, [ColorRole::Weekend, ColorRole::WeekNumber]);
+
+        let readable = palette(&[
+            (ColorRole::Weekend, "#c00000"),
+            (ColorRole::WeekNumber, "#595959"),
+        ]);
+        assert_eq!(check(&readable), []);
+    }
+
+    #[test]
+    fn suggests_readable_colors() {
+        let palette = palette(&[
+            (ColorRole::Text, "#ffff00"),
+            (ColorRole::Theme, "#87ceeb"),
+            (ColorRole::Year, "rgb(255 255 255 / 50%)"),
+            (ColorRole::Month, "#eee"),
+            (ColorRole::TodayText, "#ffffff"),
+            (ColorRole::Weekend, "#ff8888"),
+            (ColorRole::WeekNumber, "hsl(200 50% 70%)"),
+            (ColorRole::Festival, "#00000040"),
+            (ColorRole::SolarTerm, "#7fffd4"),
+        ]);
+        let warnings = check(&palette);
+        assert_eq!(warnings.len(), PAIRS.len());
+        for warning in warnings {
+            let fix = Rgba::parse(warning.fix.as_deref().unwrap()).unwrap();
+            let background = opaque(&palette, warning.pair.background).unwrap();
+            let ratio = fix.over(background).contrast(background);
+            assert!(
+                ratio >= warning.pair.minimum(),
+                "{:?}: {fix} gives {ratio}",
+                warning.pair,
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use nongli::{
+    festivals::Festival,
+    language::{Language, StaticTranslate},
+    SolarTerm,
+};
+
+/// Short explanation of a festival or a solar term.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Description<T>(pub T);
+
+impl StaticTranslate for Description<Festival> {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Festival::*;
+        use Language::*;
+        match language {
+            English => match self.0 {
+                Chunjie => "The Spring Festival, or Chinese New Year, falls on the first day of the first month. Families gather for a reunion, set off firecrackers and give out red envelopes.",
+                Yuanxiaojie => "The Lantern Festival, on the 15th day of the first month, is the first full moon of the year and ends the New Year celebrations. People admire lanterns, guess lantern riddles and eat yuanxiao.",
+                Duanwujie => "The Dragon Boat Festival, on the 5th day of the fifth month, is said to commemorate the poet Qu Yuan. People race dragon boats, eat zongzi and hang mugwort to ward off disease.",
+                Zhongqiujie => "The Mid-Autumn Festival, on the 15th day of the eighth month, celebrates the harvest under a full moon. Families gather to admire the moon and share mooncakes.",
+                NorthernXiaonian => "The Little New Year as kept in northern China, on the 23rd day of the twelfth month. Households offer sacrifices to the Kitchen God and start cleaning for the New Year.",
+                SouthernXiaonian => "The Little New Year as kept in southern China, on the 24th day of the twelfth month. Households offer sacrifices to the Kitchen God and start cleaning for the New Year.",
+                Chuxi => "New Year's Eve, the last day of the lunar year. Families share a reunion dinner and stay up late to see the new year in.",
+                _ => "",
+            },
+            ChineseSimplified => match self.0 {
+                Chunjie => "春节即农历新年，为正月初一。人们阖家团圆，放鞭炮、发红包，是最隆重的传统节日。",
+                Yuanxiaojie => "元宵节在正月十五，是新年第一个月圆之夜，也标志着春节庆祝的结束。人们赏花灯、猜灯谜、吃元宵。",
+                Duanwujie => "端午节在五月初五，相传为纪念诗人屈原。人们赛龙舟、吃粽子、挂艾草以驱邪避疫。",
+                Zhongqiujie => "中秋节在八月十五，正值秋收与满月。家人团聚赏月、分食月饼，寄托团圆之意。",
+                NorthernXiaonian => "北方小年在腊月二十三。人们祭灶神、扫尘，开始为过年做准备。",
+                SouthernXiaonian => "南方小年在腊月二十四。人们祭灶神、扫尘，开始为过年做准备。",
+                Chuxi => "除夕是农历年的最后一天。家人共进年夜饭，守岁迎接新年。",
+                _ => "",
+            },
+            ChineseTraditional => match self.0 {
+                Chunjie => "春節即農曆新年，為正月初一。人們闔家團圓，放鞭炮、發紅包，是最隆重的傳統節日。",
+                Yuanxiaojie => "元宵節在正月十五，是新年第一個月圓之夜，也標誌著春節慶祝的結束。人們賞花燈、猜燈謎、吃元宵。",
+                Duanwujie => "端午節在五月初五，相傳為紀念詩人屈原。人們賽龍舟、吃粽子、掛艾草以驅邪避疫。",
+                Zhongqiujie => "中秋節在八月十五，正值秋收與滿月。家人團聚賞月、分食月餅，寄託團圓之意。",
+                NorthernXiaonian => "北方小年在臘月二十三。人們祭灶神、掃塵，開始為過年做準備。",
+                SouthernXiaonian => "南方小年在臘月二十四。人們祭灶神、掃塵，開始為過年做準備。",
+                Chuxi => "除夕是農曆年的最後一天。家人共進年夜飯，守歲迎接新年。",
+                _ => "",
+            },
+        }
+    }
+}
+
+impl StaticTranslate for Description<SolarTerm> {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.0.as_ordinal() as usize;
+        match language {
+            Language::English => [
+                "Minor Cold. Winter enters its coldest stretch.",
+                "Major Cold. Usually the coldest time of the year, and the last solar term of the cycle.",
+                "Start of Spring. Spring begins, and so does the solar year of the Chinese calendar.",
+                "Rain Water. Rainfall increases as snow gives way to rain.",
+                "Awakening of Insects. The first spring thunder is said to wake hibernating insects.",
+                "Spring Equinox. Day and night are of equal length; days grow longer in the northern hemisphere from now on.",
+                "Pure Brightness. The weather turns clear and bright, and families sweep the tombs of their ancestors.",
+                "Grain Rain. Rain nourishes the newly sown grain. It is the last solar term of spring.",
+                "Start of Summer. Summer begins and temperatures rise noticeably.",
+                "Grain Buds. Summer grains begin to fill but are not yet ripe.",
+                "Grain in Ear. Awned crops such as wheat ripen, and it is the busy season for sowing.",
+                "Summer Solstice. The longest day and shortest night of the year in the northern hemisphere.",
+                "Minor Heat. Hot weather begins, but the peak is yet to come.",
+                "Major Heat. The hottest time of the year.",
+                "Start of Autumn. Autumn begins and the summer heat starts to fade.",
+                "End of Heat. The summer heat comes to an end.",
+                "White Dew. Nights grow cool and dew forms on the grass in the morning.",
+                "Autumn Equinox. Day and night are of equal length again; nights grow longer in the northern hemisphere from now on.",
+                "Cold Dew. The dew grows colder and is about to turn into frost.",
+                "Frost's Descent. The first frost appears. It is the last solar term of autumn.",
+                "Start of Winter. Winter begins and all things are stored away.",
+                "Minor Snow. Light snow begins to fall in the north.",
+                "Major Snow. Snowfall grows heavier and snow may cover the ground.",
+                "Winter Solstice. The shortest day and longest night of the year in the northern hemisphere, traditionally celebrated as a festival.",
+            ][ordinal],
+            Language::ChineseSimplified => [
+                "天气渐入一年中最寒冷的时段。",
+                "通常是一年中最冷的时候，也是二十四节气的最后一个。",
+                "春季开始，也是节气年的开端。",
+                "降雨开始增多，雪渐少而雨渐多。",
+                "春雷始鸣，惊醒蛰伏越冬的昆虫。",
+                "昼夜平分，此后北半球白昼渐长。",
+                "天气清朗，草木繁茂，也是扫墓祭祖的时节。",
+                "雨生百谷，是春季最后一个节气。",
+                "夏季开始，气温明显升高。",
+                "夏熟作物籽粒开始饱满，但尚未成熟。",
+                "麦类等有芒作物成熟，也是播种的繁忙时节。",
+                "北半球白昼最长、黑夜最短的一天。",
+                "天气开始炎热，但还未到最热。",
+                "一年中最炎热的时期。",
+                "秋季开始，暑气渐消。",
+                "“处”为止，意为炎热的暑天结束。",
+                "天气转凉，清晨草木上凝结白色露珠。",
+                "昼夜再次平分，此后北半球黑夜渐长。",
+                "露水更凉，将要凝结成霜。",
+                "天气渐冷，开始出现霜，是秋季最后一个节气。",
+                "冬季开始，万物收藏。",
+                "北方开始降雪，但雪量不大。",
+                "降雪量增多，地面可能积雪。",
+                "北半球白昼最短、黑夜最长的一天，民间有“冬至大如年”之说。",
+            ][ordinal],
+            Language::ChineseTraditional => [
+                "天氣漸入一年中最寒冷的時段。",
+                "通常是一年中最冷的時候，也是二十四節氣的最後一個。",
+                "春季開始，也是節氣年的開端。",
+                "降雨開始增多，雪漸少而雨漸多。",
+                "春雷始鳴，驚醒蟄伏越冬的昆蟲。",
+                "晝夜平分，此後北半球白晝漸長。",
+                "天氣清朗，草木繁茂，也是掃墓祭祖的時節。",
+                "雨生百穀，是春季最後一個節氣。",
+                "夏季開始，氣溫明顯升高。",
+                "夏熟作物籽粒開始飽滿，但尚未成熟。",
+                "麥類等有芒作物成熟，也是播種的繁忙時節。",
+                "北半球白晝最長、黑夜最短的一天。",
+                "天氣開始炎熱，但還未到最熱。",
+                "一年中最炎熱的時期。",
+                "秋季開始，暑氣漸消。",
+                "「處」為止，意為炎熱的暑天結束。",
+                "天氣轉涼，清晨草木上凝結白色露珠。",
+                "晝夜再次平分，此後北半球黑夜漸長。",
+                "露水更涼，將要凝結成霜。",
+                "天氣漸冷，開始出現霜，是秋季最後一個節氣。",
+                "冬季開始，萬物收藏。",
+                "北方開始降雪，但雪量不大。",
+                "降雪量增多，地面可能積雪。",
+                "北半球白晝最短、黑夜最長的一天，民間有「冬至大如年」之說。",
+            ][ordinal],
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use web_sys::{wasm_bindgen::JsCast, HtmlElement, KeyboardEvent};
+use yew::prelude::*;
+
+const FOCUSABLE: &str = "a[href], button:not(:disabled), input:not(:disabled), \
+    select:not(:disabled), textarea:not(:disabled), [tabindex]:not([tabindex=\"-1\"])";
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub title: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    pub close_title: AttrValue,
+    pub onclose: Callback<()>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Elements in the dialog which can take the focus, leaving out those not rendered,
+/// such as hidden ones or those inside a collapsed part.
+fn focusables(node: &NodeRef) -> Vec<HtmlElement> {
+    node.cast::<HtmlElement>()
+        .and_then(|dialog| dialog.query_selector_all(FOCUSABLE).ok())
+        .map(|list| {
+            (0..list.length())
+                .filter_map(|i| list.get(i)?.dyn_into::<HtmlElement>().ok())
+                .filter(|element| element.get_client_rects().length() > 0)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn active_element() -> Option<HtmlElement> {
+    web_sys::window()?
+        .document()?
+        .active_element()?
+        .dyn_into::<HtmlElement>()
+        .ok()
+}
+
+/// A dialog which keeps the focus inside while open, and gives it back when closed.
+#[function_component]
+pub fn DialogBox(props: &Props) -> Html {
+    let node = use_node_ref();
+    {
+        let node = node.clone();
+        use_effect_with((), move |_| {
+            let previous = active_element();
+            if let Some(first) = focusables(&node).first() {
+                let _ = first.focus();
+            }
+            move || {
+                if let Some(previous) = previous {
+                    let _ = previous.focus();
+                }
+            }
+        });
+    }
+    let onkeydown = {
+        let node = node.clone();
+        move |event: KeyboardEvent| {
+            if event.key() != "Tab" {
+                return;
+            }
+            let focusables = focusables(&node);
+            let (Some(first), Some(last)) = (focusables.first(), focusables.last()) else {
+                return;
+            };
+            let active = active_element();
+            if event.shift_key() && active.as_ref() == Some(first) {
+                event.prevent_default();
+                let _ = last.focus();
+            } else if !event.shift_key() && active.as_ref() == Some(last) {
+                event.prevent_default();
+                let _ = first.focus();
+            }
+        }
+    };
+    let onclose = props.onclose.clone();
+    html! { <div
+        ref={ node }
+        class={ classes!("dialog", props.class.clone()) }
+        role="dialog"
+        aria-modal="true"
+        aria-labelledby="dialog-title"
+        { onkeydown }
+    >
+        <div class="title" id="dialog-title">
+            { props.title.clone() }
+            <button
+                title={ props.close_title.clone() }
+                aria-label={ props.close_title.clone() }
+                class="material-symbols-outlined close"
+                onclick={ move |_| onclose.emit(()) }
+            >
+                {"close"}
+            </button>
+        </div>
+        { props.children.clone() }
+    </div> }
+}
@@ -0,0 +1,313 @@
+use std::rc::Rc;
+
+use chrono::{Month, NaiveDate};
+use nongli::language::{Language, MonthTitle, StaticTranslate, Translate};
+use web_sys::Element;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    dialog::DialogBox,
+    export::{
+        self, html as export_html,
+        layout::{self, Page},
+        pdf, png, svg,
+    },
+    form::{FileInput, Form, IntInput, Select, SelectOption},
+    model::{self, MonthPage, RangeError},
+    palette::Palette,
+    settings::{use_settings, SettingsAction},
+    sizes::{Breakpoint, Sizes},
+    translations,
+};
+
+use super::dispatch;
+
+/// Months in a row of an exported picture.
+const EXPORT_COLUMNS: usize = 3;
+
+/// Why the last export did not produce a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Failure {
+    Font(pdf::FontError),
+    Export,
+}
+
+impl StaticTranslate for Failure {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::Font(error) => error.static_translate(language),
+            Self::Export => translations::ExportFailed.static_translate(language),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// The month shown.
+    pub year: i32,
+    pub month: Month,
+    pub today: NaiveDate,
+    pub is_mobile: bool,
+    /// The `@import` rule of the Google fonts used, inlined into SVG pictures.
+    pub css_import: AttrValue,
+    /// The `main` element, saved as a web page.
+    pub main_ref: NodeRef,
+    pub onclose: Callback<()>,
+}
+
+/// Saves months as pictures, a PDF or a web page.
+#[function_component]
+pub fn ExportDialog(props: &Props) -> Html {
+    let settings = use_settings();
+    let change = dispatch(&settings);
+    let language = settings.language;
+    let failure = use_state_eq(|| None);
+    // The resolution of the last PNG image, if it was lowered to fit on a canvas.
+    let reduced_dpi = use_state_eq(|| None);
+
+    let layout_months = {
+        let today = props.today;
+        let highlight_today = settings.highlight_today;
+        let options = settings.options();
+        let time_zone = settings.time_zone;
+        Rc::new(
+            move |months: &[(i32, Month)], sizes: &Sizes, palette: &Palette| {
+                months
+                    .iter()
+                    .filter_map(|(year, month)| {
+                        MonthPage::new(*year, *month, today, highlight_today, options, time_zone)
+                    })
+                    .map(|month| layout::layout(&month, palette, sizes))
+                    .collect::<Vec<_>>()
+            },
+        )
+    };
+    let exported_months = settings
+        .export_months
+        .list((props.year, props.month), settings.print_range);
+    let export_file_name = export::file_name(&exported_months);
+    let range_error = (settings.export_months == export::Months::PrintRange)
+        .then(|| model::check_range(settings.print_range.0, settings.print_range.1).err())
+        .flatten();
+    let export_picture = {
+        let layout_months = layout_months.clone();
+        let exported_months = exported_months.clone();
+        let sizes = settings.styles.sizes[if props.is_mobile {
+            Breakpoint::Mobile
+        } else {
+            Breakpoint::Desktop
+        }]
+        .clone();
+        let palette = settings.styles.palette.clone();
+        let fonts = settings.styles.fonts.clone();
+        let css_import = props.css_import.clone();
+        Rc::new(move || {
+            let pages = layout_months(&exported_months, &sizes, &palette);
+            let page = Page::grid(&pages, pages.len().min(EXPORT_COLUMNS));
+            let svg = svg::render(&page, &fonts, &css_import);
+            (page, svg)
+        })
+    };
+    let pdf_font = &settings.pdf_font;
+    let pdf_faces = use_memo(pdf_font.clone(), |font| {
+        font.as_ref()
+            .map(|(_, data)| pdf::faces(data))
+            .unwrap_or_default()
+    });
+
+    html! { <DialogBox
+        title={ translations::Export.static_translate(language) }
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <Form>
+            <Select
+                name={ translations::ExportMonths.static_translate(language) }
+                value={ settings.export_months as u32 }
+                onchange={ {
+                    let change = change.clone();
+                    move |index| {
+                        if let Some(months) = export::Months::from_index(index) {
+                            change.emit(SettingsAction::ExportMonths(months));
+                        }
+                    }
+                } }
+            >
+                { for export::Months::ALL.iter().map(|months| html_nested! {
+                    <SelectOption>{ months.static_translate(language) }</SelectOption>
+                }) }
+            </Select>
+            <IntInput
+                name={ translations::ImageResolution.static_translate(language) }
+                min={ Some(png::CSS_DPI / 2) }
+                max={ Some(png::CSS_DPI * 6) }
+                value={ settings.image_dpi }
+                onchange={ change.reform(SettingsAction::ImageDpi) }
+            />
+            <FileInput
+                name={ translations::PdfFont.static_translate(language) }
+                accept=".ttf,.otf,.ttc,.otc"
+                file_name={
+                    pdf_font.as_ref().map(|(name, _)| AttrValue::from(name.clone()))
+                }
+                onchange={ {
+                    let change = change.clone();
+                    let failure = failure.setter();
+                    move |(name, data): (Rc<str>, Rc<[u8]>)| match pdf::check_font(&data, 0) {
+                        Ok(()) => {
+                            failure.set(None);
+                            change.emit(SettingsAction::PdfFont(name, data));
+                        }
+                        Err(error) => failure.set(Some(Failure::Font(error))),
+                    }
+                } }
+            />
+            if let Some((_, data)) = pdf_font.as_ref().filter(|_| !pdf_faces.is_empty()) {
+                <Select
+                    name={ translations::FontFace.static_translate(language) }
+                    value={ settings.pdf_face }
+                    onchange={ {
+                        let change = change.clone();
+                        let failure = failure.setter();
+                        let data = data.clone();
+                        move |index| {
+                            failure.set(pdf::check_font(&data, index).err().map(Failure::Font));
+                            change.emit(SettingsAction::PdfFace(index));
+                        }
+                    } }
+                >
+                    { for pdf_faces.iter().map(|face| html_nested! {
+                        <SelectOption>{ face }</SelectOption>
+                    }) }
+                </Select>
+            }
+        </Form>
+        if let Some(error) = range_error {
+            <p class={ if error == RangeError::Reversed { "error" } else { "notice" } }>
+                { error.translate_to_string(language) }
+            </p>
+        }
+        if let Some(dpi) = *reduced_dpi {
+            <p class="notice">{ translations::ReducedResolution(dpi).translate_to_string(language) }</p>
+        }
+        if let Some(failure) = *failure {
+            <p class="error">{ failure.static_translate(language) }</p>
+        }
+        <div class="buttons">
+            <button
+                disabled={ exported_months.is_empty() }
+                onclick={ {
+                    let export_picture = export_picture.clone();
+                    let file_name = export_file_name.clone();
+                    let failure = failure.setter();
+                    move |_| {
+                        let (_, svg) = export_picture();
+                        let saved = export::download(
+                            &format!("{file_name}.svg"),
+                            "image/svg+xml",
+                            svg.as_bytes(),
+                        );
+                        failure.set(saved.err().map(|_| Failure::Export));
+                    }
+                } }
+            >
+                {"SVG"}
+            </button>
+            <button
+                disabled={ exported_months.is_empty() }
+                onclick={ {
+                    let file_name = export_file_name.clone();
+                    let dpi = settings.image_dpi;
+                    let failure = failure.setter();
+                    let reduced_dpi = reduced_dpi.setter();
+                    move |_| {
+                        let (page, svg) = export_picture();
+                        let file_name = format!("{file_name}.png");
+                        let failure = failure.clone();
+                        let reduced_dpi = reduced_dpi.clone();
+                        spawn_local(async move {
+                            let saved = png::render(&page, &svg, dpi).await.and_then(|png| {
+                                export::download_blob(&file_name, &png.blob)?;
+                                Ok(png.dpi)
+                            });
+                            reduced_dpi.set(saved.as_ref().ok().copied().filter(|saved| *saved < dpi));
+                            failure.set(saved.err().map(|_| Failure::Export));
+                        });
+                    }
+                } }
+            >
+                {"PNG"}
+            </button>
+            <button
+                title={ translations::CurrentMonthAsWebPage.static_translate(language) }
+                onclick={ {
+                    let main_ref = props.main_ref.clone();
+                    let month_title = MonthTitle {
+                        year: props.year,
+                        month: props.month,
+                        enable_chinese: settings.enable_chinese,
+                    }
+                    .translate_to_string(language);
+                    let file_name = export::file_name(&[(props.year, props.month)]);
+                    let failure = failure.setter();
+                    move |_| {
+                        let saved = main_ref
+                            .cast::<Element>()
+                            .and_then(|main| {
+                                export_html::render(&main, &month_title, translations::language_tag(language))
+                            })
+                            .ok_or(())
+                            .and_then(|page| {
+                                export::download(
+                                    &format!("{file_name}.html"),
+                                    "text/html",
+                                    page.as_bytes(),
+                                )
+                                .map_err(|_| ())
+                            });
+                        failure.set(saved.err().map(|_| Failure::Export));
+                    }
+                } }
+            >
+                {"HTML"}
+            </button>
+            <button
+                disabled={ pdf_font.is_none() || exported_months.is_empty() }
+                title={ pdf_font
+                    .is_none()
+                    .then_some(translations::ChooseFontFirst.static_translate(language)) }
+                onclick={ {
+                    let pdf_font = pdf_font.clone();
+                    let pdf_face = settings.pdf_face;
+                    let print_setup = settings.print_setup.clone();
+                    let print_sizes = settings.styles.sizes[Breakpoint::Print].clone();
+                    let print_palette = print_setup
+                        .palette
+                        .clone()
+                        .unwrap_or_else(|| settings.styles.palette.clone());
+                    let failure = failure.setter();
+                    move |_| {
+                        let Some((_, font)) = &pdf_font else {
+                            return;
+                        };
+                        let pages =
+                            layout_months(&exported_months, &print_sizes, &print_palette);
+                        let saved = pdf::render(&pages, &print_setup, font, pdf_face)
+                            .map_err(Failure::Font)
+                            .and_then(|document| {
+                                export::download(
+                                    &format!("{export_file_name}.pdf"),
+                                    "application/pdf",
+                                    &document,
+                                )
+                                .map_err(|_| Failure::Export)
+                            });
+                        failure.set(saved.err());
+                    }
+                } }
+            >
+                {"PDF"}
+            </button>
+        </div>
+    </DialogBox> }
+}
@@ -0,0 +1,67 @@
+use chrono::{Datelike, Month, NaiveDate};
+use nongli::{
+    festivals::Festival,
+    language::{StaticTranslate, Translate},
+    ChineseDate,
+};
+use yew::prelude::*;
+
+use crate::{
+    astronomy, descriptions::Description, dialog::DialogBox, model, settings::use_settings,
+    time_zone::MonthEvents, translations,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub date: NaiveDate,
+    pub onclose: Callback<()>,
+}
+
+/// The festival, solar term and new moon of a day.
+#[function_component]
+pub fn InfoDialog(props: &Props) -> Html {
+    let settings = use_settings();
+    let language = settings.language;
+    let time_zone = settings.time_zone;
+    let date = props.date;
+    let festival = ChineseDate::from_gregorian(&date).and_then(Festival::from_chinese_date);
+    let (solar_term, new_moon) = Month::try_from(date.month() as u8)
+        .map(|month| MonthEvents::new(time_zone, date.year(), month).on(date))
+        .unwrap_or_default();
+    html! { <DialogBox
+        title={ translations::FullDate(date).translate_to_string(language) }
+        class="info"
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        if let Some(festival) = festival {
+            <div class="name festival">{ festival.static_translate(language) }</div>
+            <p>{ Description(festival).static_translate(language) }</p>
+        }
+        if let Some(solar_term) = solar_term {
+            <div class="name solar-term">{ solar_term.static_translate(language) }</div>
+            <p>{ Description(solar_term).static_translate(language) }</p>
+            <table>
+                if let Some(time) = model::solar_term_time(date, solar_term, time_zone) {
+                    <tr>
+                        <td>{ translations::ExactTime.static_translate(language) }</td>
+                        <td>{ time }</td>
+                    </tr>
+                }
+                <tr>
+                    <td>{ translations::SolarLongitude.static_translate(language) }</td>
+                    <td>{ format!("{}°", astronomy::solar_term_longitude(solar_term)) }</td>
+                </tr>
+            </table>
+        }
+        if let Some(instant) = new_moon {
+            <div class="name">{ translations::NewMoon.static_translate(language) }</div>
+            <table>
+                <tr>
+                    <td>{ translations::ExactTime.static_translate(language) }</td>
+                    <td>{ time_zone.format(instant) }</td>
+                </tr>
+            </table>
+        }
+    </DialogBox> }
+}
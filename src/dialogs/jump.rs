@@ -0,0 +1,63 @@
+use chrono::Month;
+use nongli::{
+    iter::Months,
+    language::{StaticTranslate, Translate},
+};
+use yew::prelude::*;
+
+use crate::{
+    dialog::DialogBox,
+    form::{Form, IntInput, Select, SelectOption},
+    settings::use_settings,
+    translations,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub year: i32,
+    pub month: Month,
+    pub onyear: Callback<i32>,
+    pub onmonth: Callback<Month>,
+    pub onclose: Callback<()>,
+}
+
+/// Goes to a month by its year and name.
+#[function_component]
+pub fn JumpDialog(props: &Props) -> Html {
+    let language = use_settings().language;
+    html! { <DialogBox
+        title={ translations::Jump.static_translate(language) }
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <Form>
+            <IntInput
+                name={ translations::Year.static_translate(language) }
+                min={ Some(-262143) }
+                max={ Some(262142) }
+                value={ props.year }
+                onchange={ props.onyear.clone() }
+            />
+            <Select
+                name={ translations::Month.static_translate(language) }
+                value={ props.month as u32 }
+                onchange={ {
+                    let onmonth = props.onmonth.clone();
+                    move |value| {
+                        if let Ok(month) = Month::try_from(value as u8 + 1) {
+                            onmonth.emit(month);
+                        }
+                    }
+                } }
+            >
+            {
+                for Months(Month::January).take(12).map(|month| html_nested! {
+                    <SelectOption>
+                        { month.translate_to_string(language) }
+                    </SelectOption>
+                })
+            }
+            </Select>
+        </Form>
+    </DialogBox> }
+}
@@ -0,0 +1,67 @@
+//! The dialogs of the app, which read and change the settings through
+//! [`SettingsContext`](crate::settings::SettingsContext).
+
+use std::rc::Rc;
+
+use nongli::language::{Language, StaticTranslate};
+use yew::prelude::*;
+
+pub use export::ExportDialog;
+pub use info::InfoDialog;
+pub use jump::JumpDialog;
+pub use print::PrintDialog;
+pub use settings::SettingsDialog;
+pub use shortcuts::ShortcutsDialog;
+pub use styles::StylesDialog;
+
+pub mod export;
+pub mod info;
+pub mod jump;
+pub mod print;
+pub mod settings;
+pub mod shortcuts;
+pub mod styles;
+
+use crate::{
+    color,
+    history::HistoryAction,
+    palette::{ColorRole, Palette},
+    settings::{PreferencesAction, SettingsAction, SettingsContext},
+};
+
+/// A callback dispatching the actions it is given to the settings.
+fn dispatch(settings: &SettingsContext) -> Callback<SettingsAction> {
+    let dispatcher = settings.dispatcher();
+    Callback::from(move |action| dispatcher.dispatch(action))
+}
+
+/// A callback changing the preferences, each change being undone by itself.
+fn change_preferences(settings: &SettingsContext) -> Callback<PreferencesAction> {
+    dispatch(settings).reform(|action| SettingsAction::Preferences(HistoryAction::Change(action)))
+}
+
+/// A callback for continuous input to the preferences, undone at once up to the next change.
+fn input_preferences(settings: &SettingsContext) -> Callback<PreferencesAction> {
+    dispatch(settings).reform(|action| SettingsAction::Preferences(HistoryAction::Input(action)))
+}
+
+/// Colors of the palette which color inputs offer, each once, without keywords.
+fn color_offers(palette: &Palette, language: Language) -> Rc<[(AttrValue, AttrValue)]> {
+    let mut offers = Vec::<(AttrValue, AttrValue)>::new();
+    for role in ColorRole::ALL {
+        let value = &palette[role];
+        if color::is_keyword(value)
+            || !color::is_valid(value)
+            || offers
+                .iter()
+                .any(|(_, offered)| offered.eq_ignore_ascii_case(value))
+        {
+            continue;
+        }
+        offers.push((
+            role.static_translate(language).into(),
+            AttrValue::from(value.clone()),
+        ));
+    }
+    offers.into()
+}
@@ -0,0 +1,215 @@
+use std::{ops::Deref, rc::Rc};
+
+use chrono::Month;
+use nongli::{
+    iter::Months,
+    language::{StaticTranslate, Translate},
+};
+use yew::prelude::*;
+
+use crate::{
+    dialog::DialogBox,
+    form::{CheckboxInput, ColorInput, Form, IntInput, Select, SelectOption},
+    model::{self, RangeError},
+    palette::ColorRole,
+    print::{Orientation, Paper, PrintSetupAction},
+    settings::{use_settings, PreferencesAction, SettingsAction},
+    translations,
+};
+
+use super::{change_preferences, color_offers, dispatch, input_preferences};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// Prints the months of the print range.
+    pub onprintrange: Callback<()>,
+    pub onclose: Callback<()>,
+}
+
+/// The paper, the colors for printing and the months printed by Print Range.
+#[function_component]
+pub fn PrintDialog(props: &Props) -> Html {
+    let settings = use_settings();
+    let change = dispatch(&settings);
+    let setup = change_preferences(&settings).reform(PreferencesAction::PrintSetup);
+    let setup_input = input_preferences(&settings).reform(PreferencesAction::PrintSetup);
+    let language = settings.language;
+    let print_setup = &settings.print_setup;
+    let (from, to) = settings.print_range;
+    let range_error = model::check_range(from, to).err();
+    let print_color_offers = use_memo(
+        (print_setup.palette.clone(), language),
+        |(palette, language)| {
+            palette
+                .as_ref()
+                .map(|palette| color_offers(palette, *language))
+                .unwrap_or_default()
+        },
+    );
+    html! { <DialogBox
+        title={ translations::PrintSetup.static_translate(language) }
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <Form>
+            <Select
+                name={ translations::PaperSize.static_translate(language) }
+                value={ Paper::ALL.iter().position(|paper| *paper == print_setup.paper).unwrap_or(0) as u32 }
+                onchange={ {
+                    let setup = setup.clone();
+                    move |index| {
+                        if let Some(paper) = Paper::from_index(index) {
+                            setup.emit(PrintSetupAction::Paper(paper));
+                        }
+                    }
+                } }
+            >
+            {
+                for Paper::ALL.iter().map(|paper| html_nested! {
+                    <SelectOption>{ paper.static_translate(language) }</SelectOption>
+                })
+            }
+            </Select>
+            if print_setup.paper == Paper::Custom {
+                <IntInput
+                    name={ translations::PaperWidth.static_translate(language) }
+                    min={ Some(1) }
+                    value={ print_setup.width }
+                    onchange={ setup.reform(PrintSetupAction::Width) }
+                />
+                <IntInput
+                    name={ translations::PaperHeight.static_translate(language) }
+                    min={ Some(1) }
+                    value={ print_setup.height }
+                    onchange={ setup.reform(PrintSetupAction::Height) }
+                />
+            }
+            <Select
+                name={ translations::Orientation.static_translate(language) }
+                value={ print_setup.orientation as u32 }
+                onchange={ {
+                    let setup = setup.clone();
+                    move |index| {
+                        if let Some(orientation) = Orientation::from_index(index) {
+                            setup.emit(PrintSetupAction::Orientation(orientation));
+                        }
+                    }
+                } }
+            >
+            {
+                for Orientation::ALL.iter().map(|orientation| html_nested! {
+                    <SelectOption>{ orientation.static_translate(language) }</SelectOption>
+                })
+            }
+            </Select>
+            <IntInput
+                name={ translations::Margin.static_translate(language) }
+                min={ Some(0) }
+                value={ print_setup.margin }
+                onchange={ setup.reform(PrintSetupAction::Margin) }
+            />
+            <CheckboxInput
+                name={ translations::ScaleToFit.static_translate(language) }
+                checked={ print_setup.scale_to_fit }
+                onchange={ setup.reform(PrintSetupAction::ScaleToFit) }
+            />
+            <CheckboxInput
+                name={ translations::SeparatePrintColors.static_translate(language) }
+                checked={ print_setup.palette.is_some() }
+                onchange={ {
+                    let palette = settings.styles.palette.clone();
+                    setup.reform(move |checked: bool| {
+                        PrintSetupAction::Palette(checked.then(|| palette.clone()))
+                    })
+                } }
+            />
+            if let Some(print_palette) = &print_setup.palette {
+                {
+                    for ColorRole::ALL.into_iter().map(|role| html! { <ColorInput
+                        name={ role.static_translate(language) }
+                        value={ print_palette[role].clone() }
+                        offers={ print_color_offers.deref().clone() }
+                        opacity_label={ translations::Opacity.static_translate(language) }
+                        invalid_message={ translations::InvalidColor.static_translate(language) }
+                        onchange={ setup.reform(move |value: String| {
+                            PrintSetupAction::Color(role, Rc::from(value))
+                        }) }
+                        oninput={ setup_input.reform(move |value: String| {
+                            PrintSetupAction::Color(role, Rc::from(value))
+                        }) }
+                    /> })
+                }
+            }
+            <IntInput
+                name={ translations::FromYear.static_translate(language) }
+                min={ Some(-262143) }
+                max={ Some(262142) }
+                value={ from.0 }
+                onchange={ change.reform(move |year| SettingsAction::PrintFrom(year, from.1)) }
+            />
+            <Select
+                name={ translations::FromMonth.static_translate(language) }
+                value={ from.1 as u32 }
+                onchange={ {
+                    let change = change.clone();
+                    move |index| {
+                        if let Ok(month) = Month::try_from(index as u8 + 1) {
+                            change.emit(SettingsAction::PrintFrom(from.0, month));
+                        }
+                    }
+                } }
+            >
+            {
+                for Months(Month::January).take(12).map(|month| html_nested! {
+                    <SelectOption>{ month.translate_to_string(language) }</SelectOption>
+                })
+            }
+            </Select>
+            <IntInput
+                name={ translations::ToYear.static_translate(language) }
+                min={ Some(-262143) }
+                max={ Some(262142) }
+                value={ to.0 }
+                onchange={ change.reform(move |year| SettingsAction::PrintTo(year, to.1)) }
+            />
+            <Select
+                name={ translations::ToMonth.static_translate(language) }
+                value={ to.1 as u32 }
+                onchange={ {
+                    let change = change.clone();
+                    move |index| {
+                        if let Ok(month) = Month::try_from(index as u8 + 1) {
+                            change.emit(SettingsAction::PrintTo(to.0, month));
+                        }
+                    }
+                } }
+            >
+            {
+                for Months(Month::January).take(12).map(|month| html_nested! {
+                    <SelectOption>{ month.translate_to_string(language) }</SelectOption>
+                })
+            }
+            </Select>
+        </Form>
+        if let Some(error) = range_error {
+            <p class={ if error == RangeError::Reversed { "error" } else { "notice" } }>
+                { error.translate_to_string(language) }
+            </p>
+        }
+        <div class="buttons">
+            <button onclick={ |_| {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.print();
+                }
+            } }>
+                { translations::Print.static_translate(language) }
+            </button>
+            <button
+                disabled={ range_error == Some(RangeError::Reversed) }
+                onclick={ props.onprintrange.reform(|_| ()) }
+            >
+                { translations::PrintRange.static_translate(language) }
+            </button>
+        </div>
+    </DialogBox> }
+}
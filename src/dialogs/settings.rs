@@ -0,0 +1,95 @@
+use nongli::language::StaticTranslate;
+use yew::prelude::*;
+
+use crate::{
+    dialog::DialogBox,
+    form::{CheckboxInput, Form, Select, SelectOption},
+    settings::{use_settings, PreferencesAction, LANGUAGES},
+    time_zone::{self, DisplayZone},
+    translations,
+};
+
+use super::change_preferences;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub onclose: Callback<()>,
+}
+
+/// The language, what the grid shows and how months change.
+#[function_component]
+pub fn SettingsDialog(props: &Props) -> Html {
+    let settings = use_settings();
+    let change = change_preferences(&settings);
+    let language = settings.language;
+    html! { <DialogBox
+        title={ translations::Settings.static_translate(language) }
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <Form>
+            <Select
+                name={ translations::Language.static_translate(language) }
+                value={ LANGUAGES.iter().position(|other| *other == language).unwrap_or(0) as u32 }
+                onchange={ {
+                    let change = change.clone();
+                    move |index| {
+                        if let Some(language) = LANGUAGES.get(index as usize) {
+                            change.emit(PreferencesAction::Language(*language));
+                        }
+                    }
+                } }
+            >
+                <SelectOption>{"English"}</SelectOption>
+                <SelectOption>{"简体中文"}</SelectOption>
+                <SelectOption>{"繁體中文"}</SelectOption>
+            </Select>
+            <CheckboxInput
+                name={ translations::EnableChineseCalendar.static_translate(language) }
+                checked={ settings.enable_chinese }
+                onchange={ change.reform(PreferencesAction::EnableChinese) }
+            />
+            <CheckboxInput
+                name={ translations::StartOnMonday.static_translate(language) }
+                checked={ settings.start_on_monday }
+                onchange={ change.reform(PreferencesAction::StartOnMonday) }
+            />
+            <CheckboxInput
+                name={ translations::ShowWeekNumbers.static_translate(language) }
+                checked={ settings.show_week_numbers }
+                onchange={ change.reform(PreferencesAction::ShowWeekNumbers) }
+            />
+            <CheckboxInput
+                name={ translations::HighlightToday.static_translate(language) }
+                checked={ settings.highlight_today }
+                onchange={ change.reform(PreferencesAction::HighlightToday) }
+            />
+            <CheckboxInput
+                name={ translations::AutoAdvance.static_translate(language) }
+                checked={ settings.auto_advance }
+                onchange={ change.reform(PreferencesAction::AutoAdvance) }
+            />
+            <CheckboxInput
+                name={ translations::WheelNavigation.static_translate(language) }
+                checked={ settings.wheel_navigation }
+                onchange={ change.reform(PreferencesAction::WheelNavigation) }
+            />
+            <Select
+                name={ translations::TimeZone.static_translate(language) }
+                value={ settings.time_zone.index() }
+                onchange={ change.reform(|index| {
+                    PreferencesAction::TimeZone(DisplayZone::from_index(index))
+                }) }
+            >
+                <SelectOption>
+                    { translations::LocalTimeZone.static_translate(language) }
+                </SelectOption>
+                {
+                    for time_zone::time_zone_names().map(|name| html_nested! {
+                        <SelectOption>{ name }</SelectOption>
+                    })
+                }
+            </Select>
+        </Form>
+    </DialogBox> }
+}
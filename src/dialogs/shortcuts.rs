@@ -0,0 +1,30 @@
+use nongli::language::StaticTranslate;
+use yew::prelude::*;
+
+use crate::{dialog::DialogBox, settings::use_settings, shortcuts, translations};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub onclose: Callback<()>,
+}
+
+/// Lists the keyboard shortcuts.
+#[function_component]
+pub fn ShortcutsDialog(props: &Props) -> Html {
+    let language = use_settings().language;
+    html! { <DialogBox
+        title={ translations::KeyboardShortcuts.static_translate(language) }
+        class="shortcuts"
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <div class="form">
+            <table>{
+                for shortcuts::HELP.iter().map(|(keys, description)| html! { <tr>
+                    <td>{ for keys.iter().map(|key| html! { <kbd>{ *key }</kbd> }) }</td>
+                    <td>{ description.static_translate(language) }</td>
+                </tr> })
+            }</table>
+        </div>
+    </DialogBox> }
+}
@@ -0,0 +1,318 @@
+use std::{ops::Deref, rc::Rc};
+
+use nongli::language::{StaticTranslate, Translate};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{
+    contrast,
+    dialog::DialogBox,
+    fonts::{self, FontRole},
+    form::{
+        CheckboxInput, ColorInput, FileInput, FontGroup, FontInput, Form, LengthInput, Reset,
+        Select, SelectOption,
+    },
+    history::HistoryAction,
+    local_fonts::{self, LocalFont},
+    palette::ColorRole,
+    settings::{use_settings, PreferencesAction, SettingsAction, StyleField, Styles, StylesAction},
+    sizes::{Breakpoint, Size},
+    translations,
+};
+
+use super::{change_preferences, color_offers, dispatch, input_preferences};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    /// Fonts uploaded into the browser.
+    pub uploaded_fonts: UseStateHandle<Vec<LocalFont>>,
+    /// Families installed on the system, once listed.
+    pub installed_fonts: UseStateHandle<Rc<[Rc<str>]>>,
+    pub is_mobile: bool,
+    pub onclose: Callback<()>,
+}
+
+/// Colors, fonts and sizes, with undo, redo and resetting to the defaults.
+#[function_component]
+pub fn StylesDialog(props: &Props) -> Html {
+    let settings = use_settings();
+    let language = settings.language;
+    let styles = &settings.styles;
+    let history = dispatch(&settings).reform(SettingsAction::Preferences);
+    let edit = change_preferences(&settings).reform(PreferencesAction::Styles);
+    let slide = input_preferences(&settings).reform(PreferencesAction::Styles);
+    let uploaded_font_unsupported = use_state_eq(|| false);
+    let size_breakpoint = use_state_eq(|| Option::<Breakpoint>::None);
+    let size_breakpoint_value = size_breakpoint.unwrap_or(if props.is_mobile {
+        Breakpoint::Mobile
+    } else {
+        Breakpoint::Desktop
+    });
+
+    let uploaded_families = props
+        .uploaded_fonts
+        .iter()
+        .map(|font| font.family.clone())
+        .collect::<Rc<[_]>>();
+    let font_groups = use_memo(
+        (
+            uploaded_families,
+            props.installed_fonts.deref().clone(),
+            styles.download_fonts,
+            language,
+        ),
+        |(uploaded_families, installed_families, download_fonts, language)| {
+            let google_families = if *download_fonts {
+                fonts::GOOGLE_FAMILIES
+                    .iter()
+                    .copied()
+                    .map(Rc::from)
+                    .collect()
+            } else {
+                Rc::from([])
+            };
+            Rc::<[FontGroup]>::from([
+                FontGroup {
+                    name: translations::UploadedFonts
+                        .static_translate(*language)
+                        .into(),
+                    families: uploaded_families.clone(),
+                    downloaded: false,
+                },
+                FontGroup {
+                    name: translations::InstalledFonts
+                        .static_translate(*language)
+                        .into(),
+                    families: installed_families.clone(),
+                    downloaded: false,
+                },
+                FontGroup {
+                    name: translations::GoogleFonts.static_translate(*language).into(),
+                    families: google_families,
+                    downloaded: true,
+                },
+            ])
+        },
+    );
+    let contrast_warnings = use_memo(styles.palette.clone(), contrast::check);
+    let color_offers = use_memo((styles.palette.clone(), language), |(palette, language)| {
+        color_offers(palette, *language)
+    });
+    let reset = |field: StyleField| Reset {
+        title: translations::ResetToDefault
+            .static_translate(language)
+            .into(),
+        disabled: styles.is_default(field),
+        onreset: edit.reform(move |_| StylesAction::Reset(field)),
+    };
+    let reload_uploaded_fonts = {
+        let uploaded_fonts = props.uploaded_fonts.setter();
+        move || {
+            let uploaded_fonts = uploaded_fonts.clone();
+            spawn_local(async move {
+                if let Ok(fonts) = local_fonts::load().await {
+                    uploaded_fonts.set(fonts);
+                }
+            });
+        }
+    };
+
+    html! { <DialogBox
+        title={ translations::Styles.static_translate(language) }
+        close_title={ translations::Close.static_translate(language) }
+        onclose={ props.onclose.clone() }
+    >
+        <Form>
+            {
+                for ColorRole::ALL.into_iter().map(|role| html! { <ColorInput
+                    name={ role.static_translate(language) }
+                    value={ styles.palette[role].clone() }
+                    offers={ color_offers.deref().clone() }
+                    opacity_label={ translations::Opacity.static_translate(language) }
+                    invalid_message={ translations::InvalidColor.static_translate(language) }
+                    onchange={ edit.reform(move |value: String| {
+                        StylesAction::Color(role, Rc::from(value))
+                    }) }
+                    oninput={ slide.reform(move |value: String| {
+                        StylesAction::Color(role, Rc::from(value))
+                    }) }
+                    reset={ reset(StyleField::Color(role)) }
+                /> })
+            }
+            {
+                for FontRole::ALL.into_iter().map(|role| html! { <FontInput
+                    name={ role.static_translate(language) }
+                    value={ styles.fonts[role].clone() }
+                    follow={
+                        (role != FontRole::Text).then_some(AttrValue::Static(
+                            translations::SameAsFont.static_translate(language),
+                        ))
+                    }
+                    groups={ font_groups.deref().clone() }
+                    onchange={ edit.reform(move |value: String| {
+                        StylesAction::Font(role, Rc::from(value))
+                    }) }
+                    reset={ reset(StyleField::Font(role)) }
+                /> })
+            }
+            if props.installed_fonts.is_empty() && local_fonts::can_list_installed() {
+                <tr>
+                    <td></td>
+                    <td>
+                        <button
+                            class="list-fonts"
+                            onclick={ {
+                                let installed_fonts = props.installed_fonts.setter();
+                                move |_| {
+                                    let installed_fonts = installed_fonts.clone();
+                                    spawn_local(async move {
+                                        if let Ok(families) = local_fonts::installed_families().await {
+                                            installed_fonts.set(families.into());
+                                        }
+                                    });
+                                }
+                            } }
+                        >
+                            { translations::ListInstalledFonts.static_translate(language) }
+                        </button>
+                    </td>
+                </tr>
+            }
+            <CheckboxInput
+                name={ translations::DownloadFontsAutomatically.static_translate(language) }
+                checked={ styles.download_fonts }
+                onchange={ edit.reform(StylesAction::DownloadFonts) }
+            />
+            <FileInput
+                name={ translations::UploadFont.static_translate(language) }
+                accept=".ttf,.otf,.woff,.woff2"
+                file_name={ None::<AttrValue> }
+                onchange={ {
+                    let reload_uploaded_fonts = reload_uploaded_fonts.clone();
+                    let uploaded_font_unsupported = uploaded_font_unsupported.setter();
+                    move |(name, data): (Rc<str>, Rc<[u8]>)| {
+                        let reload_uploaded_fonts = reload_uploaded_fonts.clone();
+                        let uploaded_font_unsupported = uploaded_font_unsupported.clone();
+                        spawn_local(async move {
+                            let font = LocalFont::new(name, data);
+                            let supported = local_fonts::register(&font).await.is_ok();
+                            uploaded_font_unsupported.set(!supported);
+                            if supported && local_fonts::save(&font).await.is_ok() {
+                                reload_uploaded_fonts();
+                            }
+                        });
+                    }
+                } }
+            />
+            {
+                for props.uploaded_fonts.iter().map(|uploaded| {
+                    let family = uploaded.family.clone();
+                    let reload_uploaded_fonts = reload_uploaded_fonts.clone();
+                    html! { <tr class="uploaded-font">
+                        <td style={ format!("font-family: {};", uploaded.css_family()) }>
+                            { uploaded.family.deref() }
+                            <div class="file-name">{ uploaded.file_name.deref() }</div>
+                        </td>
+                        <td>
+                            <button onclick={ move |_| {
+                                let family = family.clone();
+                                let reload_uploaded_fonts = reload_uploaded_fonts.clone();
+                                local_fonts::unregister(&family);
+                                spawn_local(async move {
+                                    let _ = local_fonts::delete(&family).await;
+                                    reload_uploaded_fonts();
+                                });
+                            } }>
+                                { translations::RemoveFont.static_translate(language) }
+                            </button>
+                        </td>
+                    </tr> }
+                })
+            }
+            <Select
+                name={ translations::SizesFor.static_translate(language) }
+                value={ size_breakpoint_value as u32 }
+                onchange={ {
+                    let size_breakpoint = size_breakpoint.setter();
+                    move |index| size_breakpoint.set(Breakpoint::from_index(index))
+                } }
+            >
+            {
+                for Breakpoint::ALL.iter().map(|breakpoint| html_nested! {
+                    <SelectOption>
+                        { breakpoint.static_translate(language) }
+                    </SelectOption>
+                })
+            }
+            </Select>
+            {
+                for Size::ALL.into_iter().map(|size| html! { <LengthInput
+                    name={ size.static_translate(language) }
+                    value={ styles.sizes[size_breakpoint_value][size].clone() }
+                    invalid_message={ translations::InvalidLength.static_translate(language) }
+                    onchange={ edit.reform(move |value: String| {
+                        StylesAction::Size(size_breakpoint_value, size, Rc::from(value))
+                    }) }
+                    oninput={ slide.reform(move |value: String| {
+                        StylesAction::Size(size_breakpoint_value, size, Rc::from(value))
+                    }) }
+                    reset={ reset(StyleField::Size(size_breakpoint_value, size)) }
+                /> })
+            }
+        </Form>
+        if !contrast_warnings.is_empty() {
+            <div class="contrast" role="status">
+                <div class="title">
+                    { translations::LowContrast.static_translate(language) }
+                </div>
+                {
+                    for contrast_warnings.iter().map(|warning| {
+                        let role = warning.pair.foreground;
+                        html! { <div class="warning">
+                            <span>{ warning.translate_to_string(language) }</span>
+                            if let Some(fix) = warning.fix.clone() {
+                                <button
+                                    style={ format!("--swatch: {fix};") }
+                                    onclick={ edit.reform(move |_| {
+                                        StylesAction::Color(role, fix.clone())
+                                    }) }
+                                >
+                                    <span class="swatch"></span>
+                                    { translations::FixContrast.static_translate(language) }
+                                    { " " }
+                                    { warning.fix.as_deref().unwrap_or_default() }
+                                </button>
+                            }
+                        </div> }
+                    })
+                }
+            </div>
+        }
+        if *uploaded_font_unsupported {
+            <p class="error">
+                { translations::UnsupportedFont.static_translate(language) }
+            </p>
+        }
+        <div class="buttons">
+            <button
+                title="Ctrl+Z"
+                disabled={ !settings.preferences.can_undo() }
+                onclick={ history.reform(|_| HistoryAction::Undo) }
+            >
+                { translations::Undo.static_translate(language) }
+            </button>
+            <button
+                title="Ctrl+Shift+Z"
+                disabled={ !settings.preferences.can_redo() }
+                onclick={ history.reform(|_| HistoryAction::Redo) }
+            >
+                { translations::Redo.static_translate(language) }
+            </button>
+            <button
+                disabled={ *styles == Styles::default() }
+                onclick={ edit.reform(|_| StylesAction::ResetAll) }
+            >
+                { translations::ResetAll.static_translate(language) }
+            </button>
+        </div>
+    </DialogBox> }
+}
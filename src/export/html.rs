@@ -0,0 +1,86 @@
+use web_sys::{wasm_bindgen::JsCast, CssStyleSheet, Document, Element};
+
+use super::svg::escape;
+
+/// The rules of every style sheet of the document, with `@import`s for those it may not read.
+fn style_sheets(document: &Document) -> String {
+    let mut imports = String::new();
+    let mut rules = String::new();
+    let sheets = document.style_sheets();
+    for i in 0..sheets.length() {
+        let Some(sheet) = sheets
+            .get(i)
+            .and_then(|sheet| sheet.dyn_into::<CssStyleSheet>().ok())
+        else {
+            continue;
+        };
+        match sheet.css_rules() {
+            Ok(list) => {
+                for j in 0..list.length() {
+                    if let Some(rule) = list.get(j) {
+                        let text = rule.css_text();
+                        let target = if text.starts_with("@import") {
+                            &mut imports
+                        } else {
+                            &mut rules
+                        };
+                        target.push_str(&text);
+                        target.push('\n');
+                    }
+                }
+            }
+            // Style sheets from other origins, such as web fonts, cannot be read.
+            Err(_) => {
+                if let Ok(Some(href)) = sheet.href() {
+                    imports.push_str(&format!("@import url(\"{href}\");\n"));
+                }
+            }
+        }
+    }
+    imports + &rules
+}
+
+/// A web page showing a copy of `element` styled like the app, which works without the app.
+pub fn render(element: &Element, title: &str, language: &str) -> Option<String> {
+    let document = element.owner_document()?;
+    let copy = element
+        .clone_node_with_deep(true)
+        .ok()?
+        .dyn_into::<Element>()
+        .ok()?;
+    // Announcements for screen readers repeat the title, and slides replay on opening the page.
+    if let Ok(hidden) = copy.query_selector_all("[aria-live]") {
+        for i in 0..hidden.length() {
+            if let Some(node) = hidden.get(i) {
+                if let Some(parent) = node.parent_node() {
+                    let _ = parent.remove_child(&node);
+                }
+            }
+        }
+    }
+    if let Ok(slides) = copy.query_selector_all(".slide-next, .slide-prev") {
+        for i in 0..slides.length() {
+            if let Some(slide) = slides
+                .get(i)
+                .and_then(|node| node.dyn_into::<Element>().ok())
+            {
+                let _ = slide.class_list().remove_2("slide-next", "slide-prev");
+            }
+        }
+    }
+    Some(format!(
+        "<!DOCTYPE html>\n\
+        <html lang=\"{language}\">\n\
+        <head>\n\
+        <meta charset=\"utf-8\" />\n\
+        <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\" />\n\
+        <title>{title}</title>\n\
+        <style>\n{styles}</style>\n\
+        </head>\n\
+        <body>\n{markup}\n</body>\n\
+        </html>\n",
+        title = escape(title),
+        styles = style_sheets(&document).replace("</style", "<\\/style"),
+        markup = copy.outer_html(),
+    ))
+}
@@ -0,0 +1,283 @@
+use std::rc::Rc;
+
+use chrono::Datelike;
+use nongli::calendar::Options;
+
+use crate::{
+    fonts::FontRole,
+    length,
+    model::{ChineseKind, MonthPage},
+    palette::{ColorRole, Palette},
+    sizes::{Breakpoint, Size, Sizes},
+};
+
+/// Height of a line of text relative to its size.
+const LINE_HEIGHT: f64 = 1.2;
+/// Space between months put side by side, in pixels.
+const GRID_GAP: f64 = 32.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// Something drawn on a page, in pixels from the top left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Item {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Rc<str>,
+    },
+    /// Text vertically centered on `y`.
+    Text {
+        x: f64,
+        y: f64,
+        text: String,
+        size: f64,
+        color: Rc<str>,
+        anchor: Anchor,
+        font: FontRole,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        color: Rc<str>,
+    },
+}
+
+impl Item {
+    fn translate(&mut self, dx: f64, dy: f64) {
+        let (Self::Rect { x, y, .. } | Self::Text { x, y, .. } | Self::Circle { x, y, .. }) = self;
+        *x += dx;
+        *y += dy;
+    }
+}
+
+/// A month laid out the way the page shows it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    pub width: f64,
+    pub height: f64,
+    pub items: Vec<Item>,
+}
+
+impl Page {
+    /// Puts months side by side, `columns` in a row, into one page.
+    pub fn grid(pages: &[Page], columns: usize) -> Page {
+        let columns = columns.max(1);
+        let cell_width = pages.iter().map(|page| page.width).fold(0.0, f64::max);
+        let mut items = Vec::new();
+        let mut height: f64 = 0.0;
+        let mut y = 0.0;
+        for row in pages.chunks(columns) {
+            let row_height = row.iter().map(|page| page.height).fold(0.0, f64::max);
+            for (i, page) in row.iter().enumerate() {
+                let x = (cell_width + GRID_GAP) * i as f64;
+                items.extend(page.items.iter().cloned().map(|mut item| {
+                    item.translate(x, y);
+                    item
+                }));
+            }
+            height = y + row_height;
+            y = height + GRID_GAP;
+        }
+        let columns = columns.min(pages.len());
+        Page {
+            width: cell_width * columns as f64 + GRID_GAP * columns.saturating_sub(1) as f64,
+            height,
+            items,
+        }
+    }
+}
+
+/// Resolves `inherit` and `currentColor`, which mean nothing outside the page, to `parent`.
+fn resolve(color: &Rc<str>, parent: &Rc<str>) -> Rc<str> {
+    if color.eq_ignore_ascii_case("inherit") || color.eq_ignore_ascii_case("currentcolor") {
+        parent.clone()
+    } else {
+        color.clone()
+    }
+}
+
+/// Rough width of a run of text, for placing things next to it.
+fn text_width(text: &str, size: f64) -> f64 {
+    text.chars()
+        .map(|ch| if ch.is_ascii() { 0.6 } else { 1.0 })
+        .sum::<f64>()
+        * size
+}
+
+pub fn layout(month: &MonthPage, palette: &Palette, sizes: &Sizes) -> Page {
+    let defaults = Sizes::new(Breakpoint::Desktop);
+    let size = |size: Size| {
+        length::to_px(&sizes[size])
+            .unwrap_or_else(|| length::to_px(&defaults[size]).unwrap_or_default())
+    };
+    let Options {
+        enable_chinese,
+        week_number: show_week_numbers,
+        ..
+    } = month.options;
+    let text_color = &palette[ColorRole::Text];
+    let color = |role: ColorRole| resolve(&palette[role], text_color);
+
+    let cell_width = size(Size::CellWidth);
+    let cell_height = size(Size::CellHeight);
+    let header_height = size(Size::HeaderHeight);
+    let week_number_width = if show_week_numbers {
+        size(Size::TextWeekNumber) * 3.0
+    } else {
+        0.0
+    };
+    let weekday_height = size(Size::TextWeekday) * LINE_HEIGHT;
+    let width = week_number_width + cell_width * 7.0;
+    let mut items = Vec::new();
+
+    let center = width / 2.0;
+    let year = month.header.year.to_string();
+    let year_size = size(Size::TextYear);
+    let side = text_width(&year, year_size) / 2.0 + size(Size::YearMargin);
+    let header_y = header_height / 2.0;
+    items.push(Item::Text {
+        x: center - side,
+        y: header_y,
+        text: month.header.month.name().to_string(),
+        size: size(Size::TextMonth),
+        color: color(ColorRole::Month),
+        anchor: Anchor::End,
+        font: FontRole::Month,
+    });
+    items.push(Item::Text {
+        x: center,
+        y: header_y,
+        text: year,
+        size: year_size,
+        color: color(ColorRole::Year),
+        anchor: Anchor::Middle,
+        font: FontRole::Year,
+    });
+    if let Some(translated_month) = &month.header.translated_month {
+        items.push(Item::Text {
+            x: center + side,
+            y: header_y,
+            text: translated_month.clone(),
+            size: size(Size::TextMonth),
+            color: color(ColorRole::Month),
+            anchor: Anchor::Start,
+            font: FontRole::Month,
+        });
+    }
+
+    for (i, weekday) in month.weekdays.iter().enumerate() {
+        items.push(Item::Text {
+            x: week_number_width + cell_width * (i as f64 + 0.5),
+            y: header_height + weekday_height / 2.0,
+            text: weekday.short.clone(),
+            size: size(Size::TextWeekday),
+            color: if weekday.weekend {
+                color(ColorRole::Weekend)
+            } else {
+                text_color.clone()
+            },
+            anchor: Anchor::Middle,
+            font: FontRole::Weekday,
+        });
+    }
+
+    let text_size = size(Size::Text);
+    let chinese_size = size(Size::TextChinese);
+    let block_height = text_size * LINE_HEIGHT
+        + if enable_chinese {
+            chinese_size * LINE_HEIGHT
+        } else {
+            0.0
+        };
+    let mut y = header_height + weekday_height;
+    for week in &month.weeks {
+        if show_week_numbers {
+            items.push(Item::Text {
+                x: week_number_width / 2.0,
+                y: y + cell_height / 2.0,
+                text: week.number.to_string(),
+                size: size(Size::TextWeekNumber),
+                color: color(ColorRole::WeekNumber),
+                anchor: Anchor::Middle,
+                font: FontRole::Text,
+            });
+        }
+        for (i, day) in week.days.iter().enumerate() {
+            let Some(day) = day else {
+                continue;
+            };
+            let x = week_number_width + cell_width * i as f64;
+            let cell_color = if day.today {
+                items.push(Item::Rect {
+                    x,
+                    y,
+                    width: cell_width,
+                    height: cell_height,
+                    color: color(ColorRole::Theme),
+                });
+                color(ColorRole::TodayText)
+            } else if day.weekend {
+                color(ColorRole::Weekend)
+            } else {
+                text_color.clone()
+            };
+            let top = y + (cell_height - block_height) / 2.0;
+            let day_y = top + text_size * LINE_HEIGHT / 2.0;
+            let number = day.date.day().to_string();
+            if day.new_moon.is_some() {
+                items.push(Item::Circle {
+                    x: x + cell_width / 2.0
+                        + text_width(&number, text_size) / 2.0
+                        + text_size * 0.2,
+                    y: day_y - text_size * 0.3,
+                    radius: text_size * 0.1,
+                    color: cell_color.clone(),
+                });
+            }
+            items.push(Item::Text {
+                x: x + cell_width / 2.0,
+                y: day_y,
+                text: number,
+                size: text_size,
+                color: cell_color.clone(),
+                anchor: Anchor::Middle,
+                font: FontRole::Day,
+            });
+            if let Some((text, kind)) = &day.chinese {
+                items.push(Item::Text {
+                    x: x + cell_width / 2.0,
+                    y: top + text_size * LINE_HEIGHT + chinese_size * LINE_HEIGHT / 2.0,
+                    text: text.clone(),
+                    size: chinese_size,
+                    color: match kind {
+                        ChineseKind::Date => cell_color.clone(),
+                        ChineseKind::Festival => {
+                            resolve(&palette[ColorRole::Festival], &cell_color)
+                        }
+                        ChineseKind::SolarTerm => {
+                            resolve(&palette[ColorRole::SolarTerm], &cell_color)
+                        }
+                    },
+                    anchor: Anchor::Middle,
+                    font: FontRole::Chinese,
+                });
+            }
+        }
+        y += cell_height;
+    }
+
+    Page {
+        width,
+        height: y,
+        items,
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::Month;
+use gloo_timers::callback::Timeout;
+use js_sys::{Array, Uint8Array};
+use nongli::language::{Language, StaticTranslate};
+use web_sys::{
+    wasm_bindgen::{JsCast, JsValue},
+    Blob, BlobPropertyBag, HtmlAnchorElement, Url,
+};
+
+use crate::{model, translations};
+
+pub mod html;
+pub mod layout;
+pub mod pdf;
+pub mod png;
+mod subset;
+pub mod svg;
+#[cfg(feature = "cli")]
+pub mod text;
+
+/// Which months go into an export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Months {
+    Current,
+    Year,
+    PrintRange,
+}
+
+impl Months {
+    pub const ALL: [Self; 3] = [Self::Current, Self::Year, Self::PrintRange];
+
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    pub fn list(
+        self,
+        current: (i32, Month),
+        print_range: ((i32, Month), (i32, Month)),
+    ) -> Vec<(i32, Month)> {
+        match self {
+            Self::Current => vec![current],
+            Self::Year => {
+                model::month_range((current.0, Month::January), (current.0, Month::December))
+            }
+            Self::PrintRange => model::month_range(print_range.0, print_range.1),
+        }
+    }
+}
+
+impl StaticTranslate for Months {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::Current => translations::CurrentMonth.static_translate(language),
+            Self::Year => translations::WholeYear.static_translate(language),
+            Self::PrintRange => translations::PrintRange.static_translate(language),
+        }
+    }
+}
+
+/// A file name without extension, such as `calendar-2025-01` or `calendar-2025-01-2025-12`.
+pub fn file_name(months: &[(i32, Month)]) -> String {
+    let mut name = String::from("calendar");
+    let last = months.last().filter(|_| months.len() > 1);
+    for (year, month) in months.first().into_iter().chain(last) {
+        name.push_str(&format!("-{year}-{:02}", month.number_from_month()));
+    }
+    name
+}
+
+/// Saves `data` as a file through a temporary link.
+pub fn download(file_name: &str, mime: &str, data: &[u8]) -> Result<(), JsValue> {
+    let parts = Array::of1(&Uint8Array::from(data));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    download_blob(
+        file_name,
+        &Blob::new_with_u8_array_sequence_and_options(&parts, &options)?,
+    )
+}
+
+/// Saves `blob` as a file through a temporary link.
+pub fn download_blob(file_name: &str, blob: &Blob) -> Result<(), JsValue> {
+    let url = Url::create_object_url_with_blob(blob)?;
+    download_url(file_name, &url)?;
+    Timeout::new(0, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+/// Saves the file at `url` through a temporary link.
+fn download_url(file_name: &str, url: &str) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let link = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()?;
+    link.set_href(url);
+    link.set_download(file_name);
+    link.click();
+    Ok(())
+}
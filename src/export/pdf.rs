@@ -0,0 +1,397 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use nongli::language::{Language, StaticTranslate};
+use pdf_writer::{
+    types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap},
+    Content, Filter, Finish, Name, Pdf, Rect, Ref, Str,
+};
+use ttf_parser::{fonts_in_collection, name_id, Face, GlyphId, Tag};
+
+use super::{
+    layout::{Anchor, Item, Page},
+    subset::subset,
+};
+use crate::{color::Rgba, print::PrintSetup, translations};
+
+const FONT_NAME: Name = Name(b"F1");
+/// Points per CSS pixel.
+const PT_PER_PX: f32 = 0.75;
+/// Points per millimeter.
+const PT_PER_MM: f32 = 72.0 / 25.4;
+/// Control point distance of a quarter circle drawn as a cubic Bézier curve.
+const KAPPA: f32 = 0.552_284_8;
+
+struct Font<'a> {
+    face: Face<'a>,
+    units_per_em: f32,
+    /// CIDs used so far, with the glyphs and the characters they stand for.
+    used: BTreeMap<u16, (GlyphId, char)>,
+}
+
+impl Font<'_> {
+    /// The CID a glyph is shown with.
+    ///
+    /// CID-keyed CFF fonts map CIDs to glyphs through their charset, other fonts use glyph IDs as CIDs.
+    fn cid(&self, glyph: GlyphId) -> u16 {
+        self.face
+            .tables()
+            .cff
+            .and_then(|cff| cff.glyph_cid(glyph))
+            .unwrap_or(glyph.0)
+    }
+
+    /// CIDs of the text encoded for `Identity-H`, and its width in em.
+    fn encode(&mut self, text: &str) -> (Vec<u8>, f32) {
+        let mut encoded = Vec::with_capacity(text.len() * 2);
+        let mut width = 0.0;
+        for ch in text.chars() {
+            let glyph = self.face.glyph_index(ch).unwrap_or(GlyphId(0));
+            let cid = self.cid(glyph);
+            self.used.entry(cid).or_insert((glyph, ch));
+            encoded.extend(cid.to_be_bytes());
+            width += self.face.glyph_hor_advance(glyph).unwrap_or(0) as f32;
+        }
+        (encoded, width / self.units_per_em)
+    }
+
+    /// Distance from the baseline to the middle of the text in em, positive upward.
+    fn middle(&self) -> f32 {
+        (self.face.ascender() as f32 + self.face.descender() as f32) / 2.0 / self.units_per_em
+    }
+
+    fn to_pdf_units(&self, value: f32) -> f32 {
+        value / self.units_per_em * 1000.0
+    }
+}
+
+/// Maps page pixels to points on the paper.
+struct Placement {
+    scale: f32,
+    left: f32,
+    top: f32,
+    height: f32,
+}
+
+impl Placement {
+    fn x(&self, x: f64) -> f32 {
+        self.left + x as f32 * self.scale
+    }
+
+    fn y(&self, y: f64) -> f32 {
+        self.height - (self.top + y as f32 * self.scale)
+    }
+}
+
+fn set_color(content: &mut Content, color: &str) -> bool {
+    let color = Rgba::parse(color).unwrap_or(Rgba::BLACK);
+    content.set_fill_rgb(color.red, color.green, color.blue);
+    color.alpha > 0.0
+}
+
+/// Why a font cannot be embedded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontError {
+    Unreadable,
+    /// Variable CFF outlines, which PDF cannot embed.
+    Cff2,
+}
+
+impl StaticTranslate for FontError {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::Unreadable => translations::UnreadableFont.static_translate(language),
+            Self::Cff2 => translations::Cff2Font.static_translate(language),
+        }
+    }
+}
+
+/// Parses face `index` of a font file [`render`] can embed, `0` for a file that is not a collection.
+fn parse_font(data: &[u8], index: u32) -> Result<Face<'_>, FontError> {
+    let face = Face::parse(data, index).map_err(|_| FontError::Unreadable)?;
+    if face.raw_face().table(Tag::from_bytes(b"CFF2")).is_some() {
+        return Err(FontError::Cff2);
+    }
+    Ok(face)
+}
+
+/// Checks that face `index` of `data` is a font [`render`] can use.
+pub fn check_font(data: &[u8], index: u32) -> Result<(), FontError> {
+    parse_font(data, index).map(|_| ())
+}
+
+/// Full names of the faces in a `.ttc` or `.otc` collection, empty for a single font.
+pub fn faces(data: &[u8]) -> Vec<String> {
+    (0..fonts_in_collection(data).unwrap_or(0))
+        .map(|index| {
+            Face::parse(data, index)
+                .ok()
+                .and_then(|face| {
+                    face.names()
+                        .into_iter()
+                        .filter(|name| name.name_id == name_id::FULL_NAME)
+                        .find_map(|name| name.to_string())
+                })
+                .unwrap_or_else(|| format!("#{}", index + 1))
+        })
+        .collect()
+}
+
+/// A PDF document with one page of paper for each month,
+/// drawn with face `index` of the given TrueType or OpenType font.
+///
+/// Only the outlines of the glyphs shown are embedded.
+pub fn render(
+    pages: &[Page],
+    setup: &PrintSetup,
+    font: &[u8],
+    index: u32,
+) -> Result<Vec<u8>, FontError> {
+    let face = parse_font(font, index)?;
+    let is_cff = face.tables().cff.is_some();
+    let mut font = Font {
+        units_per_em: face.units_per_em() as f32,
+        face,
+        used: BTreeMap::new(),
+    };
+
+    let (paper_width, paper_height) = setup.page_size();
+    let (paper_width, paper_height) = (
+        paper_width as f32 * PT_PER_MM,
+        paper_height as f32 * PT_PER_MM,
+    );
+    let margin = setup.margin.max(0) as f32 * PT_PER_MM;
+    let (printable_width, printable_height) = setup.printable_size();
+    let (printable_width, printable_height) = (
+        printable_width as f32 * PT_PER_MM,
+        printable_height as f32 * PT_PER_MM,
+    );
+
+    let mut pdf = Pdf::new();
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let type0_id = Ref::new(3);
+    let cid_font_id = Ref::new(4);
+    let descriptor_id = Ref::new(5);
+    let font_file_id = Ref::new(6);
+    let cmap_id = Ref::new(7);
+    let page_ids = (0..pages.len())
+        .map(|i| (Ref::new(8 + i as i32 * 2), Ref::new(9 + i as i32 * 2)))
+        .collect::<Vec<_>>();
+
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().map(|(page_id, _)| *page_id))
+        .count(pages.len() as i32);
+
+    for (page, (page_id, content_id)) in pages.iter().zip(&page_ids) {
+        let fit = (printable_width / (page.width as f32 * PT_PER_PX))
+            .min(printable_height / (page.height as f32 * PT_PER_PX));
+        let scale = PT_PER_PX
+            * if setup.scale_to_fit {
+                fit
+            } else {
+                fit.min(1.0)
+            };
+        let placement = Placement {
+            scale,
+            left: margin + (printable_width - page.width as f32 * scale) / 2.0,
+            top: margin,
+            height: paper_height,
+        };
+        let mut content = Content::new();
+        for item in &page.items {
+            match item {
+                Item::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => {
+                    if set_color(&mut content, color) {
+                        content
+                            .rect(
+                                placement.x(*x),
+                                placement.y(*y + *height),
+                                *width as f32 * scale,
+                                *height as f32 * scale,
+                            )
+                            .fill_nonzero();
+                    }
+                }
+                Item::Text {
+                    x,
+                    y,
+                    text,
+                    size,
+                    color,
+                    anchor,
+                    ..
+                } => {
+                    if !set_color(&mut content, color) {
+                        continue;
+                    }
+                    let size = *size as f32 * scale;
+                    let (encoded, width) = font.encode(text);
+                    let left = placement.x(*x)
+                        - match anchor {
+                            Anchor::Start => 0.0,
+                            Anchor::Middle => width * size / 2.0,
+                            Anchor::End => width * size,
+                        };
+                    let baseline = placement.y(*y) - font.middle() * size;
+                    content
+                        .begin_text()
+                        .set_font(FONT_NAME, size)
+                        .next_line(left, baseline)
+                        .show(Str(&encoded))
+                        .end_text();
+                }
+                Item::Circle {
+                    x,
+                    y,
+                    radius,
+                    color,
+                } => {
+                    if !set_color(&mut content, color) {
+                        continue;
+                    }
+                    let (cx, cy, r) = (placement.x(*x), placement.y(*y), *radius as f32 * scale);
+                    let k = r * KAPPA;
+                    content
+                        .move_to(cx + r, cy)
+                        .cubic_to(cx + r, cy + k, cx + k, cy + r, cx, cy + r)
+                        .cubic_to(cx - k, cy + r, cx - r, cy + k, cx - r, cy)
+                        .cubic_to(cx - r, cy - k, cx - k, cy - r, cx, cy - r)
+                        .cubic_to(cx + k, cy - r, cx + r, cy - k, cx + r, cy)
+                        .close_path()
+                        .fill_nonzero();
+                }
+            }
+        }
+        let mut pdf_page = pdf.page(*page_id);
+        pdf_page
+            .media_box(Rect::new(0.0, 0.0, paper_width, paper_height))
+            .parent(page_tree_id)
+            .contents(*content_id);
+        pdf_page.resources().fonts().pair(FONT_NAME, type0_id);
+        pdf_page.finish();
+        pdf.stream(*content_id, &content.finish());
+    }
+
+    let base_font = font
+        .face
+        .names()
+        .into_iter()
+        .filter(|name| name.name_id == name_id::POST_SCRIPT_NAME)
+        .find_map(|name| name.to_string())
+        .filter(|name| name.chars().all(|ch| ch.is_ascii_graphic()))
+        .unwrap_or_else(|| "CalendarFont".to_string());
+    let base_font = Name(base_font.as_bytes());
+    let system_info = SystemInfo {
+        registry: Str(b"Adobe"),
+        ordering: Str(b"Identity"),
+        supplement: 0,
+    };
+
+    pdf.type0_font(type0_id)
+        .base_font(base_font)
+        .encoding_predefined(Name(b"Identity-H"))
+        .descendant_font(cid_font_id)
+        .to_unicode(cmap_id);
+
+    let mut cid_font = pdf.cid_font(cid_font_id);
+    cid_font
+        .subtype(if is_cff {
+            CidFontType::Type0
+        } else {
+            CidFontType::Type2
+        })
+        .base_font(base_font)
+        .system_info(system_info)
+        .font_descriptor(descriptor_id)
+        .default_width(0.0);
+    if !is_cff {
+        cid_font.cid_to_gid_map_predefined(Name(b"Identity"));
+    }
+    let mut widths = cid_font.widths();
+    for (cid, (glyph, _)) in &font.used {
+        let advance = font.face.glyph_hor_advance(*glyph).unwrap_or(0);
+        widths.consecutive(*cid, [font.to_pdf_units(advance as f32)]);
+    }
+    widths.finish();
+    cid_font.finish();
+
+    let bbox = font.face.global_bounding_box();
+    let mut descriptor = pdf.font_descriptor(descriptor_id);
+    descriptor
+        .name(base_font)
+        .flags(FontFlags::SYMBOLIC)
+        .bbox(Rect::new(
+            font.to_pdf_units(bbox.x_min as f32),
+            font.to_pdf_units(bbox.y_min as f32),
+            font.to_pdf_units(bbox.x_max as f32),
+            font.to_pdf_units(bbox.y_max as f32),
+        ))
+        .italic_angle(font.face.italic_angle())
+        .ascent(font.to_pdf_units(font.face.ascender() as f32))
+        .descent(font.to_pdf_units(font.face.descender() as f32))
+        .cap_height(
+            font.to_pdf_units(font.face.capital_height().unwrap_or(font.face.ascender()) as f32),
+        )
+        .stem_v(80.0);
+    if is_cff {
+        descriptor.font_file3(font_file_id);
+    } else {
+        descriptor.font_file2(font_file_id);
+    }
+    descriptor.finish();
+
+    let glyphs = font
+        .used
+        .values()
+        .map(|(glyph, _)| glyph.0)
+        .collect::<BTreeSet<_>>();
+    let embedded = subset(&font.face, &glyphs).ok_or(FontError::Unreadable)?;
+    let compressed = compress_to_vec_zlib(&embedded, 6);
+    let mut font_file = pdf.stream(font_file_id, &compressed);
+    font_file.filter(Filter::FlateDecode);
+    if is_cff {
+        font_file.pair(Name(b"Subtype"), Name(b"OpenType"));
+    }
+    font_file.finish();
+
+    let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+    for (cid, (_, ch)) in &font.used {
+        cmap.pair(*cid, *ch);
+    }
+    pdf.cmap(cmap_id, &cmap.finish());
+
+    Ok(pdf.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_the_faces_of_collections() {
+        let collection = [
+            b"ttcf".as_slice(),
+            &[0, 1, 0, 0],
+            &[0, 0, 0, 1],
+            &[0, 0, 0, 16],
+        ]
+        .concat();
+        assert_eq!(faces(&collection), ["#1"]);
+        assert_eq!(check_font(&collection, 0), Err(FontError::Unreadable));
+        assert_eq!(check_font(&collection, 1), Err(FontError::Unreadable));
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(faces(b"not a font").is_empty());
+        assert_eq!(check_font(b"not a font", 0), Err(FontError::Unreadable));
+    }
+}
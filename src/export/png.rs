@@ -0,0 +1,129 @@
+use js_sys::{encode_uri_component, Promise};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    Blob, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement,
+};
+
+use super::layout::Page;
+
+/// Pixels per inch of the page as laid out.
+pub const CSS_DPI: i32 = 96;
+/// Pixels on a side of the largest canvas browsers can draw on.
+const MAX_SIDE: u32 = 16384;
+/// Pixels of the largest canvas browsers can draw on.
+const MAX_AREA: u64 = 268_435_456;
+
+/// A PNG image of a page.
+pub struct Png {
+    pub blob: Blob,
+    /// The resolution drawn at, lower than asked for if the picture would not fit on a canvas.
+    pub dpi: i32,
+}
+
+/// Width and height in pixels of the page drawn at `dpi`.
+fn canvas_size(page: &Page, dpi: i32) -> (u32, u32) {
+    let scale = dpi as f64 / CSS_DPI as f64;
+    (
+        (page.width * scale).ceil() as u32,
+        (page.height * scale).ceil() as u32,
+    )
+}
+
+/// The highest resolution up to `dpi` at which the page fits on a canvas.
+fn fitting_dpi(page: &Page, dpi: i32) -> i32 {
+    (1..=dpi)
+        .rev()
+        .find(|dpi| {
+            let (width, height) = canvas_size(page, *dpi);
+            width <= MAX_SIDE && height <= MAX_SIDE && width as u64 * height as u64 <= MAX_AREA
+        })
+        .unwrap_or(1)
+}
+
+/// Draws the SVG document of the page on an offscreen canvas at `dpi` over a white background,
+/// or at a lower resolution if the canvas would be too large, and encodes it as a PNG image.
+///
+/// An SVG drawn as an image cannot load web fonts, so text uses fonts installed on the device.
+pub async fn render(page: &Page, svg: &str, dpi: i32) -> Result<Png, JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let image = HtmlImageElement::new()?;
+    image.set_src(&format!(
+        "data:image/svg+xml;charset=utf-8,{}",
+        encode_uri_component(svg)
+    ));
+    JsFuture::from(image.decode()).await?;
+
+    let dpi = fitting_dpi(page, dpi);
+    let scale = dpi as f64 / CSS_DPI as f64;
+    let (width, height) = canvas_size(page, dpi);
+    let canvas = document
+        .create_element("canvas")?
+        .dyn_into::<HtmlCanvasElement>()?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    let context = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("no 2d context"))?
+        .dyn_into::<CanvasRenderingContext2d>()?;
+    context.scale(scale, scale)?;
+    context.set_fill_style_str("white");
+    context.fill_rect(0.0, 0.0, page.width, page.height);
+    context.draw_image_with_html_image_element_and_dw_and_dh(
+        &image,
+        0.0,
+        0.0,
+        page.width,
+        page.height,
+    )?;
+
+    let mut encoding = Ok(());
+    let promise = Promise::new(&mut |resolve, _| {
+        let callback = Closure::once_into_js(move |blob: JsValue| {
+            let _ = resolve.call1(&JsValue::UNDEFINED, &blob);
+        });
+        encoding = canvas.to_blob_with_type(callback.unchecked_ref(), "image/png");
+    });
+    encoding?;
+    // The callback is given null if the canvas cannot be encoded.
+    let blob = JsFuture::from(promise)
+        .await?
+        .dyn_into::<Blob>()
+        .map_err(|_| JsValue::from_str("the picture could not be encoded"))?;
+    Ok(Png { blob, dpi })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(width: f64, height: f64) -> Page {
+        Page {
+            width,
+            height,
+            items: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn keeps_the_resolution_that_fits() {
+        assert_eq!(fitting_dpi(&page(800.0, 600.0), CSS_DPI * 6), CSS_DPI * 6);
+    }
+
+    #[test]
+    fn lowers_the_resolution_to_fit() {
+        // 16384 pixels on the longer side.
+        let tall = page(600.0, 8192.0);
+        assert_eq!(fitting_dpi(&tall, CSS_DPI * 4), CSS_DPI * 2);
+        assert_eq!(canvas_size(&tall, CSS_DPI * 2), (1200, 16384));
+
+        let large = page(8000.0, 8000.0);
+        let dpi = fitting_dpi(&large, CSS_DPI * 4);
+        let (width, height) = canvas_size(&large, dpi);
+        assert!(width as u64 * height as u64 <= MAX_AREA);
+        let (width, height) = canvas_size(&large, dpi + 1);
+        assert!(width as u64 * height as u64 > MAX_AREA || width > MAX_SIDE);
+    }
+}
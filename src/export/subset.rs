@@ -0,0 +1,393 @@
+//! A single face of a font cut down to the glyphs a PDF shows, for embedding.
+//!
+//! Glyph IDs are kept as they are, so the widths, the character map and the text written with the
+//! whole face stay valid. Glyphs left out are emptied rather than removed.
+
+use std::collections::BTreeSet;
+
+use ttf_parser::{Face, Tag};
+
+/// Tables PDF readers use from an embedded TrueType font.
+const TRUE_TYPE_TABLES: [&[u8; 4]; 7] = [
+    b"cvt ", b"fpgm", b"head", b"hhea", b"hmtx", b"maxp", b"prep",
+];
+/// Tables kept next to the CFF outlines of an embedded OpenType font.
+const CFF_TABLES: [&[u8; 4]; 6] = [b"OS/2", b"head", b"hhea", b"hmtx", b"maxp", b"post"];
+/// The charstring of an empty glyph.
+const ENDCHAR: u8 = 14;
+/// The Top DICT operator giving the offset of the CharStrings INDEX.
+const CHAR_STRINGS: u8 = 17;
+
+fn u16_at(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn u32_at(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// The face as a standalone font file keeping only the outlines of `glyphs` and `.notdef`.
+///
+/// Returns `None` if the tables of the face cannot be read.
+pub fn subset(face: &Face, glyphs: &BTreeSet<u16>) -> Option<Vec<u8>> {
+    let raw = face.raw_face();
+    let table = |tag: &[u8; 4]| raw.table(Tag::from_bytes(tag));
+    let mut glyphs = glyphs.clone();
+    glyphs.insert(0);
+
+    let (version, mut tables) = if let Some(cff) = table(b"CFF ") {
+        let mut tables = vec![(*b"CFF ", subset_cff(cff, &glyphs)?)];
+        tables.extend(
+            CFF_TABLES
+                .iter()
+                .filter_map(|tag| Some((**tag, table(tag)?.to_vec()))),
+        );
+        (u32::from_be_bytes(*b"OTTO"), tables)
+    } else {
+        let long_loca = u16_at(table(b"head")?, 50)? != 0;
+        let (glyf, loca) = subset_glyf(
+            table(b"glyf")?,
+            table(b"loca")?,
+            long_loca,
+            face.number_of_glyphs(),
+            glyphs,
+        )?;
+        let mut tables = vec![(*b"glyf", glyf), (*b"loca", loca)];
+        tables.extend(
+            TRUE_TYPE_TABLES
+                .iter()
+                .filter_map(|tag| Some((**tag, table(tag)?.to_vec()))),
+        );
+        (0x0001_0000, tables)
+    };
+
+    let head = tables.iter_mut().find(|(tag, _)| tag == b"head")?;
+    if head.1.len() < 54 {
+        return None;
+    }
+    // The new `loca` has long offsets, and the checksum adjustment is worked out once the file is whole.
+    head.1[50..52].copy_from_slice(&1u16.to_be_bytes());
+    head.1[8..12].fill(0);
+    Some(write_sfnt(version, tables))
+}
+
+/// The outlines of `glyphs` and the components they are made of, with a `loca` table of long offsets.
+fn subset_glyf(
+    glyf: &[u8],
+    loca: &[u8],
+    long_loca: bool,
+    count: u16,
+    mut glyphs: BTreeSet<u16>,
+) -> Option<(Vec<u8>, Vec<u8>)> {
+    let offset = |glyph: u16| -> Option<usize> {
+        if long_loca {
+            Some(u32_at(loca, glyph as usize * 4)? as usize)
+        } else {
+            Some(u16_at(loca, glyph as usize * 2)? as usize * 2)
+        }
+    };
+    let outline = |glyph: u16| glyf.get(offset(glyph)?..offset(glyph + 1)?);
+
+    let mut pending = glyphs.iter().copied().collect::<Vec<_>>();
+    while let Some(glyph) = pending.pop() {
+        for component in components(outline(glyph).unwrap_or_default()) {
+            if component < count && glyphs.insert(component) {
+                pending.push(component);
+            }
+        }
+    }
+
+    let mut new_glyf = Vec::new();
+    let mut new_loca = Vec::with_capacity((count as usize + 1) * 4);
+    for glyph in 0..count {
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+        if glyphs.contains(&glyph) {
+            new_glyf.extend(outline(glyph)?);
+            new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+        }
+    }
+    new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+    Some((new_glyf, new_loca))
+}
+
+/// Glyphs a composite TrueType outline is made of.
+fn components(outline: &[u8]) -> Vec<u16> {
+    const ARGS_ARE_WORDS: u16 = 0x0001;
+    const HAS_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const HAS_X_AND_Y_SCALE: u16 = 0x0040;
+    const HAS_TWO_BY_TWO: u16 = 0x0080;
+
+    let mut found = Vec::new();
+    if u16_at(outline, 0).is_none_or(|contours| (contours as i16) >= 0) {
+        return found;
+    }
+    let mut at = 10;
+    while let (Some(flags), Some(glyph)) = (u16_at(outline, at), u16_at(outline, at + 2)) {
+        found.push(glyph);
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+        at += 4;
+        at += if flags & ARGS_ARE_WORDS != 0 { 4 } else { 2 };
+        at += if flags & HAS_SCALE != 0 {
+            2
+        } else if flags & HAS_X_AND_Y_SCALE != 0 {
+            4
+        } else if flags & HAS_TWO_BY_TWO != 0 {
+            8
+        } else {
+            0
+        };
+    }
+    found
+}
+
+/// A CFF INDEX: where its offsets and its data start, how many items it has and how wide the offsets are.
+struct Index {
+    offsets: usize,
+    data: usize,
+    count: usize,
+    offset_size: usize,
+}
+
+impl Index {
+    fn parse(cff: &[u8], at: usize) -> Option<Self> {
+        let count = u16_at(cff, at)? as usize;
+        if count == 0 {
+            return Some(Self {
+                offsets: at + 2,
+                data: at + 2,
+                count,
+                offset_size: 1,
+            });
+        }
+        let offset_size = *cff.get(at + 2)? as usize;
+        if !(1..=4).contains(&offset_size) {
+            return None;
+        }
+        let offsets = at + 3;
+        Some(Self {
+            offsets,
+            data: offsets + (count + 1) * offset_size - 1,
+            count,
+            offset_size,
+        })
+    }
+
+    /// Offset of item `i` from the start of the CFF data, `i == count` giving the end of the INDEX.
+    ///
+    /// Offsets in an INDEX count from 1, so `data` is the byte before the first item.
+    fn offset(&self, cff: &[u8], i: usize) -> Option<usize> {
+        if self.count == 0 {
+            return Some(self.data);
+        }
+        let at = self.offsets + i * self.offset_size;
+        let bytes = cff.get(at..at + self.offset_size)?;
+        Some(
+            self.data
+                + bytes
+                    .iter()
+                    .fold(0, |value, byte| value << 8 | *byte as usize),
+        )
+    }
+
+    fn item<'a>(&self, cff: &'a [u8], i: usize) -> Option<&'a [u8]> {
+        cff.get(self.offset(cff, i)?..self.offset(cff, i + 1)?)
+    }
+}
+
+/// The last integer operand given to `operator` in a DICT.
+fn dict_operand(dict: &[u8], operator: u8) -> Option<i32> {
+    let mut operand = None;
+    let mut at = 0;
+    while let Some(&b0) = dict.get(at) {
+        let b1 = dict.get(at + 1).map(|b1| *b1 as i32);
+        match b0 {
+            0..=11 | 13..=21 => {
+                if b0 == operator {
+                    return operand;
+                }
+                operand = None;
+                at += 1;
+            }
+            12 => {
+                operand = None;
+                at += 2;
+            }
+            28 => {
+                operand = Some(u16_at(dict, at + 1)? as i16 as i32);
+                at += 3;
+            }
+            29 => {
+                operand = Some(u32_at(dict, at + 1)? as i32);
+                at += 5;
+            }
+            30 => {
+                // A real number, ending with the nibble 0xf.
+                at += 1;
+                while dict
+                    .get(at)
+                    .is_some_and(|b| b & 0x0f != 0x0f && b >> 4 != 0x0f)
+                {
+                    at += 1;
+                }
+                operand = None;
+                at += 1;
+            }
+            32..=246 => {
+                operand = Some(b0 as i32 - 139);
+                at += 1;
+            }
+            247..=250 => {
+                operand = Some((b0 as i32 - 247) * 256 + b1? + 108);
+                at += 2;
+            }
+            251..=254 => {
+                operand = Some(-(b0 as i32 - 251) * 256 - b1? - 108);
+                at += 2;
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// The CFF data with the charstrings of glyphs not in `glyphs` emptied.
+///
+/// The CharStrings INDEX is rewritten in place and the room freed at its end is zeroed,
+/// so nothing else in the data moves and the zeros compress away.
+fn subset_cff(cff: &[u8], glyphs: &BTreeSet<u16>) -> Option<Vec<u8>> {
+    let names = Index::parse(cff, *cff.get(2)? as usize)?;
+    let top_dicts = Index::parse(cff, names.offset(cff, names.count)?)?;
+    let char_strings = dict_operand(top_dicts.item(cff, 0)?, CHAR_STRINGS)?;
+    let index = Index::parse(cff, usize::try_from(char_strings).ok()?)?;
+    let end = index.offset(cff, index.count)?;
+
+    let mut subset = cff.to_vec();
+    let mut at = index.offset(cff, 0)?;
+    for glyph in 0..index.count {
+        let charstring = index.item(cff, glyph)?;
+        let kept = match charstring {
+            [] => charstring,
+            _ if glyphs.contains(&(glyph as u16)) => charstring,
+            _ => &[ENDCHAR],
+        };
+        subset[at..at + kept.len()].copy_from_slice(kept);
+        at += kept.len();
+        let offset = (at - index.data).to_be_bytes();
+        let start = index.offsets + (glyph + 1) * index.offset_size;
+        subset[start..start + index.offset_size]
+            .copy_from_slice(&offset[offset.len() - index.offset_size..]);
+    }
+    subset[at..end].fill(0);
+    Some(subset)
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0, |sum: u32, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// An OpenType font file made of `tables`.
+fn write_sfnt(version: u32, mut tables: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+    tables.sort_by_key(|(tag, _)| *tag);
+    let count = tables.len() as u16;
+    let entry_selector = count.max(1).ilog2() as u16;
+    let search_range: u16 = 16 << entry_selector;
+
+    let mut font = Vec::new();
+    font.extend(version.to_be_bytes());
+    font.extend(count.to_be_bytes());
+    font.extend(search_range.to_be_bytes());
+    font.extend(entry_selector.to_be_bytes());
+    font.extend((count * 16 - search_range).to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    let mut head = None;
+    for (tag, data) in &tables {
+        if tag == b"head" {
+            head = Some(offset);
+        }
+        font.extend(tag);
+        font.extend(checksum(data).to_be_bytes());
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        font.extend(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    if let Some(head) = head {
+        let adjustment = 0xB1B0_AFBA_u32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    font
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_dict_operands() {
+        // 23 as one byte, a real number, -1000 as two bytes and 17 as a 16-bit integer.
+        let dict = [162, 15, 30, 0x1f, 18, 254, 124, 28, 0, 17, 17];
+        assert_eq!(dict_operand(&dict, 15), Some(23));
+        assert_eq!(dict_operand(&dict, 18), None);
+        assert_eq!(dict_operand(&dict, 17), Some(17));
+        assert_eq!(dict_operand(&dict, 16), None);
+        assert_eq!(dict_operand(&[254, 124, 16], 16), Some(-1000));
+    }
+
+    #[test]
+    fn empties_unused_charstrings_in_place() {
+        let cff = [
+            // Header, Name INDEX and Top DICT INDEX with CharStrings at 23.
+            &[1, 0, 4, 1][..],
+            &[0, 1, 1, 1, 2, b'A'],
+            &[0, 1, 1, 1, 5, 28, 0, 23, 17],
+            // String INDEX and Global Subr INDEX.
+            &[0, 0, 0, 0],
+            // CharStrings INDEX of three glyphs.
+            &[0, 3, 1, 1, 4, 7, 9],
+            &[1, 2, ENDCHAR, 3, 4, ENDCHAR, 5, ENDCHAR],
+        ]
+        .concat();
+        let subset = subset_cff(&cff, &BTreeSet::from([0, 2])).unwrap();
+        assert_eq!(subset[..27], cff[..27]);
+        assert_eq!(subset[27..30], [4, 5, 7]);
+        assert_eq!(subset[30..], [1, 2, ENDCHAR, ENDCHAR, 5, ENDCHAR, 0, 0]);
+    }
+
+    #[test]
+    fn finds_the_components_of_composite_glyphs() {
+        let header = [0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 0];
+        let composite = [
+            &header[..],
+            // Byte arguments and a scale, then word arguments.
+            &[0, 0x28, 0, 3, 1, 2, 0x40, 0],
+            &[0, 0x01, 0, 7, 0, 1, 0, 2],
+        ]
+        .concat();
+        assert_eq!(components(&composite), [3, 7]);
+        assert!(components(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 0]).is_empty());
+        assert!(components(&[]).is_empty());
+    }
+
+    #[test]
+    fn balances_the_checksum() {
+        let mut head = vec![0; 54];
+        head[..4].copy_from_slice(&[0, 1, 0, 0]);
+        let font = write_sfnt(
+            0x0001_0000,
+            vec![(*b"head", head), (*b"maxp", vec![1, 2, 3])],
+        );
+        assert_eq!(font.len(), 12 + 2 * 16 + 56 + 4);
+        assert_eq!(checksum(&font), 0xB1B0_AFBA);
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::Write;
+
+use super::layout::{Anchor, Item, Page};
+use crate::fonts::{FontRole, Fonts};
+
+/// Escapes text for use in XML and HTML.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// A standalone SVG document of the page.
+///
+/// `font_import` is a style sheet loading the web fonts named in `fonts`, if any.
+pub fn render(page: &Page, fonts: &Fonts, font_import: &str) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+        viewBox=\"0 0 {width} {height}\" font-family=\"{font}\">\n",
+        width = page.width,
+        height = page.height,
+        font = escape(fonts.stack(FontRole::Text)),
+    );
+    if !font_import.is_empty() {
+        let _ = writeln!(svg, "<style>{}</style>", escape(font_import));
+    }
+    for item in &page.items {
+        let _ = match item {
+            Item::Rect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\" style=\"fill: {}\"/>",
+                escape(color),
+            ),
+            Item::Text {
+                x,
+                y,
+                text,
+                size,
+                color,
+                anchor,
+                font,
+            } => writeln!(
+                svg,
+                "<text x=\"{x}\" y=\"{y}\" font-size=\"{size}\" text-anchor=\"{}\" \
+                dominant-baseline=\"central\" style=\"fill: {}; font-family: {}\">{}</text>",
+                match anchor {
+                    Anchor::Start => "start",
+                    Anchor::Middle => "middle",
+                    Anchor::End => "end",
+                },
+                escape(color),
+                escape(fonts.stack(*font)),
+                escape(text),
+            ),
+            Item::Circle {
+                x,
+                y,
+                radius,
+                color,
+            } => writeln!(
+                svg,
+                "<circle cx=\"{x}\" cy=\"{y}\" r=\"{radius}\" style=\"fill: {}\"/>",
+                escape(color),
+            ),
+        };
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
@@ -0,0 +1,405 @@
+//! Months as text for terminals, the way the `cal` program prints them.
+
+use std::fmt::Write;
+
+use anstyle::Style;
+use chrono::Datelike;
+use nongli::{
+    calendar::Options,
+    cli_calendar::{
+        Aligned, Alignment::Center, ZipByLine, CELL_WIDTH_WITHOUT_CHINESE, CELL_WIDTH_WITH_CHINESE,
+        FESTIVAL_COLOR, NEW_MONTH_COLOR, SOLAR_TERM_COLOR, WEEKEND_COLOR, WHITE,
+    },
+    language::{Language, Translate, YearTitle},
+    ChineseDate,
+};
+
+use crate::model::{ChineseKind, Day, MonthPage};
+
+/// Weeks printed for every month, so that months side by side line up.
+const WEEKS: usize = 6;
+
+fn cell_width(options: &Options) -> usize {
+    if options.enable_chinese {
+        CELL_WIDTH_WITH_CHINESE
+    } else {
+        CELL_WIDTH_WITHOUT_CHINESE
+    }
+}
+
+fn month_width(options: &Options) -> usize {
+    cell_width(options) * 7 + if options.week_number { 4 } else { 0 }
+}
+
+/// A festival or solar term cut down to fit a cell, as `nongli` shortens them.
+fn shorten(name: &str, language: Language) -> String {
+    let (limit, keep, ellipsis) = match language {
+        Language::English => (6, 5, "."),
+        _ => (3, 2, ".."),
+    };
+    if name.chars().count() > limit {
+        name.chars().take(keep).chain(ellipsis.chars()).collect()
+    } else {
+        name.to_owned()
+    }
+}
+
+fn blank(text: &mut String, width: usize) {
+    text.extend(std::iter::repeat_n(' ', width));
+}
+
+fn weekday_line(text: &mut String, page: &MonthPage) {
+    let options = page.options;
+    let inverted = Style::new().invert();
+    if options.week_number {
+        if options.color {
+            let _ = write!(text, "{}    {}", inverted.render(), inverted.render_reset());
+        } else {
+            blank(text, 4);
+        }
+    }
+    for weekday in &page.weekdays {
+        let centered = Aligned(&weekday.short, Center, cell_width(&options));
+        if options.color {
+            let style = if weekday.weekend {
+                Style::new().bg_color(Some(WEEKEND_COLOR))
+            } else {
+                Style::new()
+            }
+            .invert();
+            let _ = write!(text, "{}{centered}{}", style.render(), style.render_reset());
+        } else {
+            let _ = write!(text, "{centered}");
+        }
+    }
+}
+
+fn day_style(day: &Day, color: Option<anstyle::Color>) -> Style {
+    let color = color.or(day.weekend.then_some(WEEKEND_COLOR));
+    match (day.today, color) {
+        (true, Some(color)) => Style::new().fg_color(Some(color)).bg_color(Some(WHITE)),
+        (true, None) => Style::new().invert(),
+        (false, color) => Style::new().fg_color(color),
+    }
+}
+
+/// The line below the day numbers: the festival, the solar term or the Chinese date.
+fn chinese_cell(day: &Day, language: Language) -> (String, Option<anstyle::Color>) {
+    match &day.chinese {
+        Some((name, ChineseKind::Festival)) => (shorten(name, language), Some(FESTIVAL_COLOR)),
+        Some((name, ChineseKind::SolarTerm)) => (shorten(name, language), Some(SOLAR_TERM_COLOR)),
+        Some((date, ChineseKind::Date)) => {
+            let new_month = ChineseDate::from_gregorian(&day.date)
+                .is_some_and(|chinese_date| chinese_date.day() == 1);
+            (date.clone(), new_month.then_some(NEW_MONTH_COLOR))
+        }
+        None => (String::new(), None),
+    }
+}
+
+/// The weeks of a month, padded to [`WEEKS`] weeks.
+fn weeks(page: &MonthPage) -> String {
+    let options = page.options;
+    let cell_width = cell_width(&options);
+    let inverted = Style::new().invert();
+    let mut text = String::new();
+    for week in &page.weeks {
+        if options.week_number {
+            if options.color {
+                let _ = write!(
+                    text,
+                    "{} {:02} {}",
+                    inverted.render(),
+                    week.number,
+                    inverted.render_reset()
+                );
+            } else {
+                let _ = write!(text, "|{:02}|", week.number);
+            }
+        }
+        for day in &week.days {
+            let Some(day) = day else {
+                blank(&mut text, cell_width);
+                continue;
+            };
+            let number = day.date.day();
+            if options.color {
+                let style = day_style(day, None);
+                let _ = write!(
+                    text,
+                    "{}{number:^cell_width$}{}",
+                    style.render(),
+                    style.render_reset()
+                );
+            } else if day.today {
+                let _ = write!(text, "[{number:^0$}]", cell_width - 2);
+            } else {
+                let _ = write!(text, "{number:^cell_width$}");
+            }
+        }
+        text.push('\n');
+        if options.enable_chinese {
+            if options.week_number {
+                blank(&mut text, 4);
+            }
+            for day in &week.days {
+                let Some(day) = day else {
+                    blank(&mut text, cell_width);
+                    continue;
+                };
+                let (chinese, color) = chinese_cell(day, options.language);
+                let centered = Aligned(&chinese, Center, cell_width);
+                if options.color {
+                    let style = day_style(day, color);
+                    let _ = write!(text, "{}{centered}{}", style.render(), style.render_reset());
+                } else {
+                    let _ = write!(text, "{centered}");
+                }
+            }
+            text.push('\n');
+        }
+    }
+    for _ in page.weeks.len()..WEEKS {
+        let lines = if options.enable_chinese { 2 } else { 1 };
+        for _ in 0..lines {
+            blank(&mut text, month_width(&options));
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Months side by side, each under its title, which names the year too if `with_year`.
+pub fn months(pages: &[MonthPage], with_year: bool) -> String {
+    let Some(first) = pages.first() else {
+        return String::new();
+    };
+    let options = first.options;
+    let mut text = String::new();
+    for (index, page) in pages.iter().enumerate() {
+        if index > 0 {
+            text.push(' ');
+        }
+        let title = if with_year {
+            page.title.clone()
+        } else {
+            page.header.month.translate_to_string(options.language)
+        };
+        let _ = write!(text, "{}", Aligned(title, Center, month_width(&options)));
+    }
+    text.push('\n');
+    let inverted = Style::new().invert();
+    for (index, page) in pages.iter().enumerate() {
+        if index > 0 {
+            if options.color {
+                let _ = write!(text, "{} {}", inverted.render(), inverted.render_reset());
+            } else {
+                text.push(' ');
+            }
+        }
+        weekday_line(&mut text, page);
+    }
+    text.push('\n');
+
+    let lines = WEEKS * if options.enable_chinese { 2 } else { 1 };
+    let separator = " \n".repeat(lines);
+    let weeks = pages.iter().map(weeks).collect::<Vec<_>>();
+    let mut columns = Vec::new();
+    for (index, weeks) in weeks.iter().enumerate() {
+        if index > 0 {
+            columns.push(separator.as_str());
+        }
+        columns.push(weeks.as_str());
+    }
+    let _ = write!(text, "{}", ZipByLine(&columns));
+    text
+}
+
+/// The twelve months of `year` in rows of `columns`, under the title of the year.
+pub fn year(year: i32, pages: &[MonthPage], columns: usize) -> String {
+    let Some(first) = pages.first() else {
+        return String::new();
+    };
+    let options = first.options;
+    let width = (month_width(&options) + 1) * columns - 1;
+    let title = YearTitle {
+        year,
+        enable_chinese: options.enable_chinese,
+    }
+    .translate_to_string(options.language);
+    let mut text = format!("{}\n", Aligned(title, Center, width));
+    for row in pages.chunks(columns) {
+        text.push_str(&months(row, false));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+    use chrono_tz::Tz;
+
+    use super::*;
+    use crate::time_zone::DisplayZone;
+
+    fn options(
+        language: Language,
+        enable_chinese: bool,
+        week_number: bool,
+        color: bool,
+    ) -> Options {
+        Options {
+            language,
+            enable_chinese,
+            start_on_monday: false,
+            week_number,
+            color,
+        }
+    }
+
+    /// Months of 2025 from `first`, printed on January 29, 2025 in Shanghai.
+    fn pages(first: Month, count: usize, options: Options) -> Vec<MonthPage> {
+        let today = NaiveDate::from_ymd_opt(2025, 1, 29).unwrap();
+        let zone = DisplayZone(Some(Tz::Asia__Shanghai));
+        (first.number_from_month()..first.number_from_month() + count as u32)
+            .map(|month| {
+                let month = Month::try_from(month as u8).unwrap();
+                MonthPage::new(2025, month, today, true, options, zone).unwrap()
+            })
+            .collect()
+    }
+
+    fn lines(text: &str) -> Vec<&str> {
+        text.lines().collect()
+    }
+
+    #[test]
+    fn prints_months_side_by_side() {
+        let text = months(
+            &pages(
+                Month::January,
+                2,
+                options(Language::English, false, false, false),
+            ),
+            true,
+        );
+        let lines = lines(&text);
+        assert_eq!(
+            lines[..7],
+            [
+                "        January 2025                February 2025        ",
+                "Sun Mon Tue Wed Thu Fri Sat  Sun Mon Tue Wed Thu Fri Sat ",
+                "             1   2   3   4                            1  ",
+                " 5   6   7   8   9   10  11   2   3   4   5   6   7   8  ",
+                " 12  13  14  15  16  17  18   9   10  11  12  13  14  15 ",
+                " 19  20  21  22  23  24  25   16  17  18  19  20  21  22 ",
+                " 26  27  28 [29] 30  31       23  24  25  26  27  28     ",
+            ]
+        );
+        // Six weeks for every month, so that the next row of months lines up.
+        assert_eq!(lines.len(), 8);
+        assert!(lines.iter().all(|line| line.chars().count() == 57));
+        assert!(!text.contains('\x1b'));
+    }
+
+    #[test]
+    fn prints_week_numbers() {
+        let text = months(
+            &pages(
+                Month::January,
+                2,
+                options(Language::English, false, true, false),
+            ),
+            true,
+        );
+        let lines = lines(&text);
+        assert_eq!(
+            lines[1],
+            "    Sun Mon Tue Wed Thu Fri Sat      Sun Mon Tue Wed Thu Fri Sat "
+        );
+        assert!(lines[2].starts_with("|01|"));
+        assert!(lines[2].contains("|05|"));
+        assert!(lines[6].starts_with("|05| 26"));
+        assert!(lines.iter().all(|line| line.chars().count() == 65));
+    }
+
+    #[test]
+    fn prints_the_chinese_calendar() {
+        let text = months(
+            &pages(
+                Month::January,
+                1,
+                options(Language::ChineseSimplified, true, true, false),
+            ),
+            true,
+        );
+        let lines = lines(&text);
+        assert!(lines[0].contains("2025年 一月"));
+        // A line of Chinese dates under each week.
+        assert_eq!(lines.len(), 2 + 2 * WEEKS);
+        assert!(lines[5].contains("小寒"));
+        assert!(lines[11].contains("除夕") && lines[11].contains("春节"));
+        assert!(lines[10].contains("[ 29 ]"));
+
+        let text = months(
+            &pages(
+                Month::January,
+                1,
+                options(Language::ChineseSimplified, false, true, false),
+            ),
+            true,
+        );
+        assert_eq!(text.lines().count(), 2 + WEEKS);
+        assert!(!text.contains("春节"));
+    }
+
+    #[test]
+    fn colors_when_asked() {
+        for (enable_chinese, week_number) in [(false, false), (true, true)] {
+            let text = months(
+                &pages(
+                    Month::January,
+                    2,
+                    options(Language::English, enable_chinese, week_number, true),
+                ),
+                true,
+            );
+            assert!(text.contains('\x1b'));
+            // Today is inverted rather than put in brackets.
+            assert!(!text.contains("[29]") && !text.contains("[ 29 ]"));
+            let weekend = Style::new()
+                .fg_color(Some(WEEKEND_COLOR))
+                .render()
+                .to_string();
+            assert!(text.contains(&weekend));
+        }
+    }
+
+    #[test]
+    fn prints_a_year_in_rows() {
+        let pages = pages(
+            Month::January,
+            12,
+            options(Language::English, false, false, false),
+        );
+        let text = year(2025, &pages, 3);
+        let lines = lines(&text);
+        assert_eq!(lines[0].trim(), "2025");
+        assert_eq!(lines[0].chars().count(), 29 * 3 - 1);
+        assert_eq!(lines.len(), 1 + 4 * (2 + WEEKS));
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            ["January", "February", "March"]
+        );
+        assert_eq!(
+            lines[1 + 3 * (2 + WEEKS)]
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["October", "November", "December"]
+        );
+
+        let text = year(2025, &pages, 4);
+        assert_eq!(text.lines().count(), 1 + 3 * (2 + WEEKS));
+        assert!(year(2025, &[], 3).is_empty());
+    }
+}
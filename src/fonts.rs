@@ -0,0 +1,140 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    rc::Rc,
+};
+
+use crate::roles::roles;
+
+const GENERIC_FAMILIES: &[&str] = &[
+    "cursive",
+    "emoji",
+    "fangsong",
+    "fantasy",
+    "inherit",
+    "initial",
+    "kai",
+    "math",
+    "monospace",
+    "nastaliq",
+    "revert",
+    "revert-layer",
+    "sans-serif",
+    "serif",
+    "system-ui",
+    "ui-monospace",
+    "ui-rounded",
+    "ui-sans-serif",
+    "ui-serif",
+    "unset",
+];
+
+/// Families from Google Fonts offered by the font picker, Latin ones first.
+pub const GOOGLE_FAMILIES: &[&str] = &[
+    "Inter",
+    "Lato",
+    "Merriweather",
+    "Noto Sans",
+    "Noto Serif",
+    "Open Sans",
+    "Playfair Display",
+    "Roboto",
+    "LXGW WenKai TC",
+    "Ma Shan Zheng",
+    "Noto Sans SC",
+    "Noto Sans TC",
+    "Noto Serif SC",
+    "Noto Serif TC",
+    "ZCOOL XiaoWei",
+];
+
+roles! {
+    pub enum FontRole {
+        Text => "--font-family", Font;
+        Year => "--font-year", YearFont;
+        Month => "--font-month", MonthFont;
+        Weekday => "--font-weekday", WeekdayFont;
+        Day => "--font-day", DayFont;
+        Chinese => "--font-chinese", ChineseFont;
+    }
+    /// Font stacks of all roles, indexed by [`FontRole`]; an empty one follows [`FontRole::Text`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Fonts;
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        Self(["sans-serif", "", "", "", "", ""].map(Rc::from))
+    }
+}
+
+impl Fonts {
+    /// The stack text of the role is drawn with.
+    pub fn stack(&self, role: FontRole) -> &str {
+        match &self[role] {
+            stack if stack.trim().is_empty() => &self[FontRole::Text],
+            stack => stack,
+        }
+    }
+
+    /// Families named in any of the stacks, each once.
+    pub fn families(&self) -> Vec<&str> {
+        let mut families = Vec::new();
+        for family in self.0.iter().flat_map(|stack| families_of(stack)) {
+            if !families.contains(&family) {
+                families.push(family);
+            }
+        }
+        families
+    }
+}
+
+/// The font variables, with roles left empty pointing at `--font-family`.
+impl Display for Fonts {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        for role in FontRole::ALL {
+            if role != FontRole::Text && self[role].trim().is_empty() {
+                writeln!(f, "{}: var(--font-family);", role.variable())?;
+            } else {
+                writeln!(f, "{}: {};", role.variable(), self[role])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Families named in a stack, without the generic ones.
+pub fn families_of(stack: &str) -> impl Iterator<Item = &str> {
+    stack
+        .split(',')
+        .map(|family| family.trim().trim_matches(['"', '\'']))
+        .filter(|family| {
+            !family.is_empty() && !GENERIC_FAMILIES.contains(&family.to_lowercase().as_str())
+        })
+}
+
+/// A stack of the family, keeping the generic fallback of `stack`.
+pub fn with_family(stack: &str, family: &str) -> String {
+    let fallback = stack
+        .split(',')
+        .map(str::trim)
+        .rfind(|family| GENERIC_FAMILIES.contains(&family.to_lowercase().as_str()))
+        .unwrap_or("sans-serif");
+    format!("\"{family}\", {fallback}")
+}
+
+/// A style sheet loading the families from Google Fonts, empty if there are none.
+pub fn google_import<'a>(families: impl IntoIterator<Item = &'a str>) -> String {
+    let families = families
+        .into_iter()
+        .flat_map(|family| {
+            "&family="
+                .chars()
+                .chain(family.chars().map(|ch| if ch == ' ' { '+' } else { ch }))
+        })
+        .collect::<String>();
+    if families.is_empty() {
+        String::new()
+    } else {
+        format!("@import url(\"https://fonts.googleapis.com/css2?display=swap{families}\");")
+    }
+}
@@ -1,50 +1,151 @@
-use std::ops::Deref;
+use std::{ops::Deref, rc::Rc};
 
-use web_sys::{wasm_bindgen::JsCast, HtmlInputElement};
+use web_sys::{wasm_bindgen::JsCast, EventTarget, HtmlInputElement};
 use yew::prelude::*;
 
+use super::{name_cell, Reset};
+use crate::color::{self, Rgba};
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct Props {
     pub name: AttrValue,
-    #[prop_or(AttrValue::Static("text"))]
-    pub r#type: AttrValue,
+    /// Any CSS color, or one of [`color::KEYWORDS`].
     pub value: AttrValue,
+    /// Colors which can be picked with one click, with their names.
+    #[prop_or_default]
+    pub offers: Rc<[(AttrValue, AttrValue)]>,
+    pub opacity_label: AttrValue,
+    /// Shown while the value is not a color.
+    pub invalid_message: AttrValue,
     pub onchange: Callback<String>,
+    /// Called while the opacity slider is dragged, before `onchange` is called where it is let go.
+    #[prop_or_default]
+    pub oninput: Option<Callback<String>>,
+    #[prop_or_default]
+    pub reset: Option<Reset>,
 }
 
+/// A color as text with a swatch, a picker, an opacity slider and colors to pick from.
 #[function_component]
 pub fn ColorInput(props: &Props) -> Html {
-    let onchange = props.onchange.clone();
-    let onchange1 = props.onchange.clone();
-    html! { <tr>
-        <td>{ props.name.deref() }</td>
-        <td>
-            <input
-                type="text"
-                value={ props.value.clone() }
-                onchange={ move |event: Event| {
-                    if let Some(element) = event
-                        .target()
-                        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
-                    {
-                        onchange.emit(element.value());
+    let rgba = Rgba::parse(&props.value);
+    let keyword = color::is_keyword(&props.value);
+    let invalid = !color::is_valid(&props.value);
+    let emit = |value: String| {
+        let onchange = props.onchange.clone();
+        Callback::from(move |_: MouseEvent| onchange.emit(value.clone()))
+    };
+    let ontext = {
+        let onchange = props.onchange.clone();
+        move |event: Event| {
+            if let Some(element) = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+            {
+                onchange.emit(element.value());
+            }
+        }
+    };
+    let onpick = {
+        let onchange = props.onchange.clone();
+        let alpha = rgba.map_or(1.0, |rgba| rgba.alpha);
+        move |event: Event| {
+            if let Some(picked) = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                .and_then(|element| Rgba::parse(&element.value()))
+            {
+                onchange.emit(Rgba { alpha, ..picked }.to_string());
+            }
+        }
+    };
+    let onopacity = |callback: Callback<String>| {
+        move |target: Option<EventTarget>| {
+            if let (Some(rgba), Some(opacity)) = (
+                rgba,
+                target
+                    .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                    .map(|element| element.value_as_number())
+                    .filter(|opacity| opacity.is_finite()),
+            ) {
+                callback.emit(
+                    Rgba {
+                        alpha: opacity as f32 / 100.0,
+                        ..rgba
                     }
-                } }
-            />
-        </td>
+                    .to_string(),
+                );
+            }
+        }
+    };
+    let onopacityinput = {
+        let onopacity = onopacity(props.oninput.clone().unwrap_or(props.onchange.clone()));
+        move |event: InputEvent| onopacity(event.target())
+    };
+    let onopacitychange = {
+        let onopacity = onopacity(props.onchange.clone());
+        move |event: Event| onopacity(event.target())
+    };
+    html! { <tr class="color-input">
+        { name_cell(&props.name, props.reset.as_ref()) }
         <td>
+            <div class="color">
+                <span
+                    class={ classes!("swatch", keyword.then_some("keyword"), invalid.then_some("invalid")) }
+                    style={ (!keyword && !invalid).then(|| format!("--swatch: {};", props.value)) }
+                    aria-hidden="true"
+                ></span>
+                <input
+                    type="text"
+                    class={ classes!(invalid.then_some("invalid")) }
+                    aria-invalid={ invalid.then_some("true") }
+                    aria-label={ props.name.clone() }
+                    value={ props.value.clone() }
+                    onchange={ ontext }
+                />
+                <input
+                    type="color"
+                    aria-label={ props.name.clone() }
+                    disabled={ rgba.is_none() }
+                    value={ rgba.unwrap_or(Rgba::BLACK).to_hex() }
+                    onchange={ onpick }
+                />
+            </div>
             <input
-                type="color"
-                value={ props.value.clone() }
-                onchange={ move |event: Event| {
-                    if let Some(element) = event
-                        .target()
-                        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
-                    {
-                        onchange1.emit(element.value());
-                    }
-                } }
+                type="range"
+                class="opacity"
+                min="0"
+                max="100"
+                title={ props.opacity_label.clone() }
+                aria-label={ props.opacity_label.clone() }
+                disabled={ rgba.is_none() }
+                value={ rgba.map_or(100.0, |rgba| (rgba.alpha * 100.0).round()).to_string() }
+                oninput={ onopacityinput }
+                onchange={ onopacitychange }
             />
+            <div class="offers">
+                {
+                    for color::KEYWORDS.iter().map(|keyword| html! {
+                        <button class="keyword" onclick={ emit(keyword.to_string()) }>
+                            { keyword }
+                        </button>
+                    })
+                }
+                {
+                    for props.offers.iter().map(|(name, value)| html! {
+                        <button
+                            class="swatch"
+                            style={ format!("--swatch: {value};") }
+                            title={ format!("{name}: {value}") }
+                            aria-label={ format!("{name}: {value}") }
+                            onclick={ emit(value.to_string()) }
+                        ></button>
+                    })
+                }
+            </div>
+            if invalid {
+                <div class="invalid">{ props.invalid_message.deref() }</div>
+            }
         </td>
     </tr> }
 }
@@ -0,0 +1,50 @@
+use std::{ops::Deref, rc::Rc};
+
+use js_sys::Uint8Array;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{wasm_bindgen::JsCast, HtmlInputElement};
+use yew::{platform::spawn_local, prelude::*};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub name: AttrValue,
+    pub accept: AttrValue,
+    /// Name of the file chosen before, since the input forgets it when the dialog closes.
+    pub file_name: Option<AttrValue>,
+    /// Called with the name and contents of the chosen file.
+    pub onchange: Callback<(Rc<str>, Rc<[u8]>)>,
+}
+
+#[function_component]
+pub fn FileInput(props: &Props) -> Html {
+    let onchange = props.onchange.clone();
+    html! { <tr>
+        <td>{ props.name.deref() }</td>
+        <td>
+            <input
+                type="file"
+                accept={ props.accept.clone() }
+                onchange={ move |event: Event| {
+                    let Some(file) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                        .and_then(|element| element.files())
+                        .and_then(|files| files.get(0))
+                    else {
+                        return;
+                    };
+                    let onchange = onchange.clone();
+                    spawn_local(async move {
+                        if let Ok(buffer) = JsFuture::from(file.array_buffer()).await {
+                            let data = Uint8Array::new(&buffer).to_vec();
+                            onchange.emit((file.name().into(), data.into()));
+                        }
+                    });
+                } }
+            />
+            if let Some(file_name) = &props.file_name {
+                <div class="file-name">{ file_name.deref() }</div>
+            }
+        </td>
+    </tr> }
+}
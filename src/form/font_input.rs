@@ -0,0 +1,147 @@
+use std::{ops::Deref, rc::Rc};
+
+use web_sys::{wasm_bindgen::JsCast, HtmlInputElement};
+use yew::prelude::*;
+
+use super::{name_cell, Reset};
+use crate::fonts;
+
+/// Text shown next to each family, with digits and Chinese characters.
+const SAMPLE: &str = "2025 正月初一";
+
+/// Families offered under a heading.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontGroup {
+    pub name: AttrValue,
+    pub families: Rc<[Rc<str>]>,
+    /// Whether the families come from Google Fonts, which is only asked for one once it is
+    /// pointed at or focused in the list.
+    pub downloaded: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub name: AttrValue,
+    /// The font stack, which can also be typed.
+    pub value: AttrValue,
+    /// Label of the empty value, if the font can follow another one.
+    #[prop_or_default]
+    pub follow: Option<AttrValue>,
+    pub groups: Rc<[FontGroup]>,
+    pub onchange: Callback<String>,
+    #[prop_or_default]
+    pub reset: Option<Reset>,
+}
+
+/// A font stack field with a list of families, each previewed in itself.
+#[function_component]
+pub fn FontInput(props: &Props) -> Html {
+    let open = use_state_eq(|| false);
+    // Downloaded families loaded so far for their previews.
+    let previewed = use_state_eq(Vec::<Rc<str>>::new);
+    let open_setter = open.setter();
+    let onchange = props.onchange.clone();
+    let current = fonts::families_of(&props.value).next();
+    let summary = match (current, &props.follow) {
+        (Some(family), _) => family.to_string(),
+        (None, Some(follow)) if props.value.trim().is_empty() => follow.to_string(),
+        (None, _) => props.value.to_string(),
+    };
+    let choose = {
+        let onchange = props.onchange.clone();
+        let open_setter = open.setter();
+        move |value: String| {
+            let onchange = onchange.clone();
+            let open_setter = open_setter.clone();
+            Callback::from(move |_: MouseEvent| {
+                open_setter.set(false);
+                onchange.emit(value.clone());
+            })
+        }
+    };
+    html! { <tr class="font-input">
+        { name_cell(&props.name, props.reset.as_ref()) }
+        <td>
+            <input
+                type="text"
+                value={ props.value.clone() }
+                onchange={ move |event: Event| {
+                    if let Some(element) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                    {
+                        onchange.emit(element.value());
+                    }
+                } }
+            />
+            <details open={ *open }>
+                <summary
+                    style={ (!props.value.trim().is_empty())
+                        .then(|| format!("font-family: {};", props.value)) }
+                    onclick={ {
+                        let open = *open;
+                        move |event: MouseEvent| {
+                            event.prevent_default();
+                            open_setter.set(!open);
+                        }
+                    } }
+                >
+                    { summary }
+                </summary>
+                if *open {
+                    {
+                        for previewed.iter().map(|family| html! {
+                            <style>{ fonts::google_import([family.deref()]) }</style>
+                        })
+                    }
+                    <div class="font-list">
+                        if let Some(follow) = &props.follow {
+                            <button
+                                class={ classes!(
+                                    props.value.trim().is_empty().then_some("selected"),
+                                ) }
+                                onclick={ choose(String::new()) }
+                            >
+                                { follow.deref() }
+                            </button>
+                        }
+                        {
+                            for props.groups.iter().filter(|group| !group.families.is_empty()).map(|group| html! { <>
+                                <div class="group">{ group.name.deref() }</div>
+                                {
+                                    for group.families.iter().map(|family| {
+                                        let preview = group.downloaded.then(|| {
+                                            let previewed = previewed.clone();
+                                            let family = family.clone();
+                                            Callback::from(move |()| {
+                                                if !previewed.contains(&family) {
+                                                    let mut families = (*previewed).clone();
+                                                    families.push(family.clone());
+                                                    previewed.set(families);
+                                                }
+                                            })
+                                        });
+                                        html! { <button
+                                            class={ classes!(
+                                                (current == Some(family.deref())).then_some("selected"),
+                                            ) }
+                                            style={ format!("font-family: \"{family}\";") }
+                                            onpointerenter={
+                                                preview.clone().map(|preview| preview.reform(|_: PointerEvent| ()))
+                                            }
+                                            onfocus={ preview.map(|preview| preview.reform(|_: FocusEvent| ())) }
+                                            onclick={ choose(fonts::with_family(&props.value, family)) }
+                                        >
+                                            { family.deref() }
+                                            <span class="sample">{ SAMPLE }</span>
+                                        </button> }
+                                    })
+                                }
+                            </> })
+                        }
+                    </div>
+                }
+            </details>
+        </td>
+    </tr> }
+}
@@ -0,0 +1,136 @@
+use std::ops::Deref;
+
+use web_sys::{wasm_bindgen::JsCast, EventTarget, HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use super::{name_cell, Reset};
+use crate::length::{Length, EDITED_UNITS};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub name: AttrValue,
+    /// A CSS length such as `24px`.
+    pub value: AttrValue,
+    /// Shown while the typed number or the value is not a length.
+    pub invalid_message: AttrValue,
+    pub onchange: Callback<String>,
+    /// Called while the slider is dragged, before `onchange` is called where it is let go.
+    #[prop_or_default]
+    pub oninput: Option<Callback<String>>,
+    #[prop_or_default]
+    pub reset: Option<Reset>,
+}
+
+/// A length as a number, a unit and a slider, which only gives valid lengths.
+#[function_component]
+pub fn LengthInput(props: &Props) -> Html {
+    // A typed number that is not valid, kept so that it can be corrected.
+    let draft = use_state_eq(|| Option::<String>::None);
+    let length = Length::parse(&props.value).filter(|length| length.value >= 0.0);
+    let invalid = draft.is_some() || length.is_none();
+    let current = length.unwrap_or(Length {
+        value: 0.0,
+        unit: "px",
+    });
+    let (max, step) = current.slider();
+    let units = EDITED_UNITS
+        .iter()
+        .map(|(unit, _, _)| *unit)
+        .chain(
+            (!EDITED_UNITS
+                .iter()
+                .any(|(unit, _, _)| *unit == current.unit))
+            .then_some(current.unit),
+        )
+        .collect::<Vec<_>>();
+    let onnumber = {
+        let draft = draft.clone();
+        let onchange = props.onchange.clone();
+        move |event: Event| {
+            let Some(element) = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+            else {
+                return;
+            };
+            let text = element.value();
+            match text.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() && value >= 0.0 => {
+                    draft.set(None);
+                    onchange.emit(Length { value, ..current }.to_string());
+                }
+                _ => draft.set(Some(text)),
+            }
+        }
+    };
+    let onunit = {
+        let units = units.clone();
+        let onchange = props.onchange.clone();
+        move |event: Event| {
+            if let Some(unit) = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlSelectElement>().ok())
+                .and_then(|element| units.get(element.selected_index().max(0) as usize))
+            {
+                onchange.emit(current.convert(unit).to_string());
+            }
+        }
+    };
+    let onslide = |callback: Callback<String>| {
+        let draft = draft.clone();
+        move |target: Option<EventTarget>| {
+            if let Some(value) = target
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                .map(|element| element.value_as_number())
+                .filter(|value| value.is_finite())
+            {
+                draft.set(None);
+                callback.emit(Length { value, ..current }.to_string());
+            }
+        }
+    };
+    let onslideinput = {
+        let onslide = onslide(props.oninput.clone().unwrap_or(props.onchange.clone()));
+        move |event: InputEvent| onslide(event.target())
+    };
+    let onslidechange = {
+        let onslide = onslide(props.onchange.clone());
+        move |event: Event| onslide(event.target())
+    };
+    html! { <tr class="length-input">
+        { name_cell(&props.name, props.reset.as_ref()) }
+        <td>
+            <div class="length">
+                <input
+                    type="number"
+                    min="0"
+                    step="any"
+                    class={ classes!(invalid.then_some("invalid")) }
+                    aria-invalid={ invalid.then_some("true") }
+                    value={ draft.deref().clone().unwrap_or_else(|| current.value.to_string()) }
+                    onchange={ onnumber }
+                />
+                <select aria-label={ props.name.clone() } onchange={ onunit }>
+                {
+                    for units.iter().map(|unit| html! {
+                        <option selected={ *unit == current.unit }>{ unit }</option>
+                    })
+                }
+                </select>
+            </div>
+            <input
+                type="range"
+                min="0"
+                max={ max.max(current.value).to_string() }
+                step={ step.to_string() }
+                aria-label={ props.name.clone() }
+                value={ current.value.to_string() }
+                oninput={ onslideinput }
+                onchange={ onslidechange }
+            />
+            if invalid {
+                <div class="invalid">{ props.invalid_message.deref() }</div>
+            }
+        </td>
+    </tr> }
+}
@@ -1,15 +1,23 @@
+use std::ops::Deref;
+
 use html::ChildrenProps;
 use yew::prelude::*;
 
 pub use checkbox_input::CheckboxInput;
 pub use color_input::ColorInput;
+pub use file_input::FileInput;
+pub use font_input::{FontGroup, FontInput};
 pub use int_input::IntInput;
+pub use length_input::LengthInput;
 pub use select::{Select, SelectOption};
 pub use string_input::StringInput;
 
 pub mod checkbox_input;
 pub mod color_input;
+pub mod file_input;
+pub mod font_input;
 pub mod int_input;
+pub mod length_input;
 pub mod select;
 pub mod string_input;
 
@@ -19,3 +27,30 @@ pub fn Form(props: &ChildrenProps) -> Html {
         <table>{ props.children.clone() }</table>
     </div> }
 }
+
+/// A button putting a field back to its default value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reset {
+    pub title: AttrValue,
+    /// Whether the field has its default value already.
+    pub disabled: bool,
+    pub onreset: Callback<()>,
+}
+
+/// The cell naming a field, with its reset button if any.
+fn name_cell(name: &AttrValue, reset: Option<&Reset>) -> Html {
+    html! { <td>
+        { name.deref() }
+        if let Some(reset) = reset {
+            <button
+                class="reset material-symbols-outlined"
+                title={ reset.title.clone() }
+                aria-label={ format!("{}: {name}", reset.title) }
+                disabled={ reset.disabled }
+                onclick={ reset.onreset.reform(|_| ()) }
+            >
+                { "restart_alt" }
+            </button>
+        }
+    </td> }
+}
@@ -0,0 +1,94 @@
+use web_sys::{TouchEvent, WheelEvent};
+
+/// Horizontal distance in pixels a touch must travel to count as a swipe.
+const SWIPE_DISTANCE: i32 = 48;
+/// Accumulated wheel distance in pixels which turns a page.
+const WHEEL_DISTANCE: f64 = 96.0;
+/// Milliseconds to ignore the wheel after turning a page, so that a flick only turns one page.
+const WHEEL_COOLDOWN: f64 = 400.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Prev,
+    Next,
+}
+
+fn touch_point(event: &TouchEvent) -> Option<(i32, i32)> {
+    let touch = event.changed_touches().get(0)?;
+    Some((touch.client_x(), touch.client_y()))
+}
+
+/// Tracks a touch from start to end, recognizing horizontal swipes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Swipe {
+    start: Option<(i32, i32)>,
+}
+
+impl Swipe {
+    pub fn start(&mut self, event: &TouchEvent) {
+        self.start = (event.touches().length() == 1)
+            .then(|| touch_point(event))
+            .flatten();
+    }
+
+    pub fn cancel(&mut self) {
+        self.start = None;
+    }
+
+    /// Swiping to the left shows the next month, like turning a page.
+    pub fn end(&mut self, event: &TouchEvent) -> Option<Direction> {
+        let (start_x, start_y) = self.start.take()?;
+        let (end_x, end_y) = touch_point(event)?;
+        let (dx, dy) = (end_x - start_x, end_y - start_y);
+        if dx.abs() < SWIPE_DISTANCE || dx.abs() < dy.abs() * 2 {
+            return None;
+        }
+        Some(if dx < 0 {
+            Direction::Next
+        } else {
+            Direction::Prev
+        })
+    }
+}
+
+/// Accumulates wheel deltas, so that a notched wheel and a touchpad turn pages alike.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Wheel {
+    delta: f64,
+    last_turn: f64,
+}
+
+impl Wheel {
+    /// Takes the event from the page, so that it does not scroll as well, unless it zooms with Ctrl.
+    pub fn scroll(&mut self, event: &WheelEvent) -> Option<Direction> {
+        if event.ctrl_key() {
+            return None;
+        }
+        event.prevent_default();
+        let now = js_sys::Date::now();
+        if now - self.last_turn < WHEEL_COOLDOWN {
+            return None;
+        }
+        let delta = match event.delta_mode() {
+            WheelEvent::DOM_DELTA_LINE => 32.0,
+            WheelEvent::DOM_DELTA_PAGE => WHEEL_DISTANCE,
+            _ => 1.0,
+        } * if event.delta_y().abs() >= event.delta_x().abs() {
+            event.delta_y()
+        } else {
+            event.delta_x()
+        };
+        self.delta += delta;
+        if self.delta.abs() < WHEEL_DISTANCE {
+            return None;
+        }
+        let direction = if self.delta > 0.0 {
+            Direction::Next
+        } else {
+            Direction::Prev
+        };
+        self.delta = 0.0;
+        self.last_turn = now;
+        Some(direction)
+    }
+}
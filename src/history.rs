@@ -0,0 +1,200 @@
+//! Undo and redo of the changes made to a value.
+
+use std::{fmt::Debug, mem, ops::Deref, rc::Rc};
+
+use yew::Reducible;
+
+/// Steps kept for undoing.
+const LIMIT: usize = 100;
+
+/// A value changed by actions which can be undone.
+pub trait Undoable: Clone + Debug + PartialEq {
+    type Change;
+    /// What a change is made to; continuous input to the same field, such as
+    /// dragging a slider, is undone at once.
+    type Field: Clone + Debug + PartialEq;
+
+    fn field(change: &Self::Change) -> Option<Self::Field>;
+    fn apply(&self, change: Self::Change) -> Self;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistoryAction<C> {
+    /// A change which can be undone by itself, or which ends the input to its field.
+    Change(C),
+    /// A step of continuous input; the steps to one field until the next change are
+    /// undone together.
+    Input(C),
+    Undo,
+    Redo,
+}
+
+/// A value with the ones it had before and the ones undone since.
+#[derive(Clone, Debug, PartialEq)]
+pub struct History<T: Undoable> {
+    past: Vec<T>,
+    present: T,
+    future: Vec<T>,
+    /// The field taking continuous input, which is in one step of the past.
+    inputting: Option<T::Field>,
+}
+
+impl<T: Undoable> History<T> {
+    pub fn new(present: T) -> Self {
+        Self {
+            past: Vec::new(),
+            present,
+            future: Vec::new(),
+            inputting: None,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    /// Makes the change, in the same step as the input to its field so far if there is any.
+    fn change(&mut self, change: T::Change, input: bool) {
+        let field = T::field(&change);
+        let continued = field.is_some() && field == self.inputting;
+        let present = self.present.apply(change);
+        let changed = present != self.present;
+        if changed {
+            let previous = mem::replace(&mut self.present, present);
+            if !continued {
+                self.past.push(previous);
+                if self.past.len() > LIMIT {
+                    self.past.remove(0);
+                }
+            }
+            self.future.clear();
+        }
+        self.inputting = field.filter(|_| input && (continued || changed));
+    }
+}
+
+impl<T: Undoable> Deref for History<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.present
+    }
+}
+
+impl<T: Undoable> Reducible for History<T> {
+    type Action = HistoryAction<T::Change>;
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut history = Rc::unwrap_or_clone(self);
+        match action {
+            HistoryAction::Change(change) => history.change(change, false),
+            HistoryAction::Input(change) => history.change(change, true),
+            HistoryAction::Undo => {
+                if let Some(previous) = history.past.pop() {
+                    let present = mem::replace(&mut history.present, previous);
+                    history.future.push(present);
+                    history.inputting = None;
+                }
+            }
+            HistoryAction::Redo => {
+                if let Some(next) = history.future.pop() {
+                    let present = mem::replace(&mut history.present, next);
+                    history.past.push(present);
+                    history.inputting = None;
+                }
+            }
+        }
+        Rc::new(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two numbers, set by `(index, value)`.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pair([i32; 2]);
+
+    impl Undoable for Pair {
+        type Change = (usize, i32);
+        type Field = usize;
+
+        fn field(change: &(usize, i32)) -> Option<usize> {
+            Some(change.0)
+        }
+
+        fn apply(&self, (index, value): (usize, i32)) -> Self {
+            let mut pair = self.clone();
+            pair.0[index] = value;
+            pair
+        }
+    }
+
+    fn run(actions: impl IntoIterator<Item = HistoryAction<(usize, i32)>>) -> History<Pair> {
+        actions
+            .into_iter()
+            .fold(Rc::new(History::new(Pair([0, 0]))), Reducible::reduce)
+            .as_ref()
+            .clone()
+    }
+
+    fn undo_all(mut history: History<Pair>) -> Vec<Pair> {
+        let mut states = vec![history.present.clone()];
+        while history.can_undo() {
+            history = Rc::unwrap_or_clone(Rc::new(history).reduce(HistoryAction::Undo));
+            states.push(history.present.clone());
+        }
+        states
+    }
+
+    #[test]
+    fn undoes_changes_one_by_one() {
+        use HistoryAction::Change;
+        let history = run([Change((0, 1)), Change((0, 2)), Change((1, 3))]);
+        assert_eq!(
+            undo_all(history),
+            [Pair([2, 3]), Pair([2, 0]), Pair([1, 0]), Pair([0, 0])],
+        );
+    }
+
+    #[test]
+    fn undoes_input_at_once_until_the_change() {
+        use HistoryAction::{Change, Input};
+        let history = run([
+            Input((0, 1)),
+            Input((0, 2)),
+            Change((0, 3)),
+            Input((0, 4)),
+            Input((1, 5)),
+            Input((1, 6)),
+        ]);
+        assert_eq!(
+            undo_all(history),
+            [Pair([4, 6]), Pair([4, 0]), Pair([3, 0]), Pair([0, 0])],
+        );
+    }
+
+    #[test]
+    fn starts_input_anew_after_undo() {
+        use HistoryAction::{Input, Redo, Undo};
+        let history = run([Input((0, 1)), Undo, Input((0, 2)), Input((0, 3))]);
+        assert!(!history.can_redo());
+        assert_eq!(undo_all(history), [Pair([3, 0]), Pair([0, 0])]);
+
+        let history = run([Input((0, 1)), Undo, Redo, Input((0, 2))]);
+        assert_eq!(
+            undo_all(history),
+            [Pair([2, 0]), Pair([1, 0]), Pair([0, 0])],
+        );
+    }
+
+    #[test]
+    fn keeps_no_step_for_input_without_effect() {
+        use HistoryAction::Input;
+        let history = run([Input((0, 0)), Input((0, 1))]);
+        assert_eq!(undo_all(history), [Pair([1, 0]), Pair([0, 0])]);
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::{Datelike, Month, Months, NaiveDate};
+use web_sys::UrlSearchParams;
+
+/// A month shown in kiosk mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KioskMonth {
+    /// Months after the current one, or before it if negative.
+    Relative(i32),
+    Absolute(i32, Month),
+}
+
+/// Configuration of kiosk mode, read from the query string, e.g.
+/// `?kiosk&months=0,1,2025-12&interval=60`.
+///
+/// The rotation shows one month at a time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Kiosk {
+    pub months: Vec<KioskMonth>,
+    /// Seconds before moving to the next month.
+    pub interval: u32,
+}
+
+impl KioskMonth {
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if let Some((year, month)) = s.rsplit_once('-').filter(|(year, _)| !year.is_empty()) {
+            let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+            Some(Self::Absolute(year.parse().ok()?, month))
+        } else {
+            s.trim_start_matches('+').parse().ok().map(Self::Relative)
+        }
+    }
+
+    pub fn resolve(self, today: NaiveDate) -> Option<(i32, Month)> {
+        match self {
+            Self::Relative(offset) => {
+                let first = today.with_day(1)?;
+                let months = Months::new(offset.unsigned_abs());
+                let date = if offset < 0 {
+                    first.checked_sub_months(months)
+                } else {
+                    first.checked_add_months(months)
+                }?;
+                Some((date.year(), Month::try_from(date.month() as u8).ok()?))
+            }
+            Self::Absolute(year, month) => {
+                NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).map(|_| (year, month))
+            }
+        }
+    }
+}
+
+impl Kiosk {
+    pub fn from_location() -> Option<Self> {
+        // There is no location when pre-rendering.
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let search = web_sys::window()?.location().search().ok()?;
+        let params = UrlSearchParams::new_with_str(&search).ok()?;
+        if !params.has("kiosk") {
+            return None;
+        }
+        let months = params
+            .get("months")
+            .map(|months| {
+                months
+                    .split(',')
+                    .filter_map(KioskMonth::parse)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|months| !months.is_empty())
+            .unwrap_or_else(|| vec![KioskMonth::Relative(0)]);
+        let interval = params
+            .get("interval")
+            .and_then(|interval| interval.parse().ok())
+            .filter(|interval| *interval > 0)
+            .unwrap_or(30);
+        Some(Self { months, interval })
+    }
+}
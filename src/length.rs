@@ -0,0 +1,158 @@
+use std::{
+    cmp::Reverse,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Pixels per CSS unit, for the absolute units and the font-relative ones at the default font size.
+const UNITS: &[(&str, f64)] = &[
+    ("px", 1.0),
+    ("pt", 96.0 / 72.0),
+    ("pc", 16.0),
+    ("in", 96.0),
+    ("cm", 96.0 / 2.54),
+    ("mm", 96.0 / 25.4),
+    ("q", 96.0 / 101.6),
+    ("rem", 16.0),
+    ("em", 16.0),
+];
+
+/// Pixels per unit, `None` for the units that depend on the window.
+fn px_per(unit: &str) -> Option<f64> {
+    UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|(_, px)| *px)
+}
+
+/// Converts a CSS length such as `96px` or `1.5cm` to pixels.
+///
+/// Viewport and percentage lengths have no meaning outside the page, so they give `None`.
+pub fn to_px(length: &str) -> Option<f64> {
+    let length = Length::parse(length)?;
+    Some(length.value * px_per(length.unit)?)
+}
+
+/// Units that depend on the size of the window, kept when editing but not converted.
+const VIEWPORT_UNITS: &[&str] = &["vw", "vh", "vmin", "vmax", "svw", "svh", "dvw", "dvh"];
+
+/// Units offered when editing a length, with the largest value and the step of their sliders.
+pub const EDITED_UNITS: &[(&str, f64, f64)] = &[
+    ("px", 256.0, 1.0),
+    ("pt", 192.0, 1.0),
+    ("mm", 64.0, 0.5),
+    ("em", 16.0, 0.125),
+    ("rem", 16.0, 0.125),
+    ("vw", 25.0, 0.25),
+];
+
+/// A length taken apart into its number and unit, for editing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub unit: &'static str,
+}
+
+impl Length {
+    /// Reads a length with a known unit, giving `None` for anything else, such as a typo.
+    pub fn parse(length: &str) -> Option<Self> {
+        let length = length.trim().to_ascii_lowercase();
+        if length == "0" {
+            return Some(Self {
+                value: 0.0,
+                unit: "px",
+            });
+        }
+        // Longer units first, so that `rem` is not read as `em` and `vmin` as `in`.
+        let mut units = UNITS
+            .iter()
+            .map(|(unit, _)| *unit)
+            .chain(VIEWPORT_UNITS.iter().copied())
+            .collect::<Vec<_>>();
+        units.sort_by_key(|unit| Reverse(unit.len()));
+        units.into_iter().find_map(|unit| {
+            length
+                .strip_suffix(unit)
+                .and_then(|number| number.trim_end().parse::<f64>().ok())
+                .filter(|number| number.is_finite())
+                .map(|value| Self { value, unit })
+        })
+    }
+
+    /// The same length in another unit, or the same number in it if either unit depends on the window.
+    pub fn convert(self, unit: &'static str) -> Self {
+        let value = match (px_per(self.unit), px_per(unit)) {
+            (Some(from), Some(to)) => (self.value * from / to * 1000.0).round() / 1000.0,
+            _ => self.value,
+        };
+        Self { value, unit }
+    }
+
+    /// The largest value and the step of the slider for the unit.
+    ///
+    /// Units not offered for editing get a slider around the current value.
+    pub fn slider(self) -> (f64, f64) {
+        EDITED_UNITS
+            .iter()
+            .find(|(unit, _, _)| *unit == self.unit)
+            .map_or(((self.value * 2.0).max(1.0), 0.01), |(_, max, step)| {
+                (*max, *step)
+            })
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(length: &str) -> Option<(f64, &'static str)> {
+        Length::parse(length).map(|length| (length.value, length.unit))
+    }
+
+    #[test]
+    fn parses_lengths() {
+        assert_eq!(parsed("24px"), Some((24.0, "px")));
+        assert_eq!(parsed(" 1.5 EM "), Some((1.5, "em")));
+        assert_eq!(parsed("0"), Some((0.0, "px")));
+        assert_eq!(parsed("-2mm"), Some((-2.0, "mm")));
+    }
+
+    #[test]
+    fn matches_the_longest_unit() {
+        assert_eq!(parsed("1.5rem"), Some((1.5, "rem")));
+        assert_eq!(parsed("2vmin"), Some((2.0, "vmin")));
+        assert_eq!(parsed("2vmax"), Some((2.0, "vmax")));
+        assert_eq!(parsed("2vw"), Some((2.0, "vw")));
+        assert_eq!(parsed("3svh"), Some((3.0, "svh")));
+        assert_eq!(parsed("1in"), Some((1.0, "in")));
+    }
+
+    #[test]
+    fn rejects_typos() {
+        for typo in [
+            "", "px", "12", "12pz", "12 p x", "1..5em", "remm", "infpx", "NaNpx", "12%",
+        ] {
+            assert_eq!(parsed(typo), None, "{typo}");
+        }
+    }
+
+    #[test]
+    fn converts_between_units() {
+        assert_eq!(to_px("1in"), Some(96.0));
+        assert_eq!(to_px("2rem"), Some(32.0));
+        assert_eq!(to_px("1vmin"), None);
+        assert_eq!(to_px(" 0 "), Some(0.0));
+        assert_eq!(to_px("12pz"), None);
+        let inch = Length {
+            value: 1.0,
+            unit: "in",
+        };
+        assert_eq!(inch.convert("mm").value, 25.4);
+        assert_eq!(inch.convert("vw").value, 1.0);
+    }
+}
@@ -0,0 +1,28 @@
+pub mod app;
+pub mod astronomy;
+pub mod clock;
+pub mod color;
+pub mod contrast;
+pub mod descriptions;
+pub mod dialog;
+pub mod dialogs;
+pub mod export;
+pub mod fonts;
+pub mod form;
+pub mod gesture;
+pub mod history;
+pub mod kiosk;
+pub mod length;
+pub mod local_fonts;
+pub mod model;
+pub mod month;
+pub mod palette;
+pub mod prerender;
+pub mod print;
+mod roles;
+pub mod service_worker;
+pub mod settings;
+pub mod shortcuts;
+pub mod sizes;
+pub mod time_zone;
+pub mod translations;
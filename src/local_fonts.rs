@@ -0,0 +1,210 @@
+//! Fonts uploaded by the user, kept in IndexedDB and registered with the document,
+//! so that they can be used without downloading fonts from elsewhere, and the families
+//! installed on the device.
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use js_sys::{Array, Function, Object, Promise, Reflect, Uint8Array};
+use ttf_parser::{name_id, Face};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    FontFace, IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+};
+
+const DATABASE: &str = "calendar";
+const STORE: &str = "fonts";
+
+thread_local! {
+    /// Faces registered with the document, by family.
+    static FACES: RefCell<HashMap<Rc<str>, FontFace>> = RefCell::default();
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalFont {
+    pub family: Rc<str>,
+    pub file_name: Rc<str>,
+    pub data: Rc<[u8]>,
+}
+
+impl LocalFont {
+    /// Takes the family from the names in the font, or from the file name for compressed fonts.
+    pub fn new(file_name: Rc<str>, data: Rc<[u8]>) -> Self {
+        let family = Face::parse(&data, 0)
+            .ok()
+            .and_then(|face| {
+                let names = face.names();
+                [name_id::TYPOGRAPHIC_FAMILY, name_id::FAMILY]
+                    .into_iter()
+                    .find_map(|id| {
+                        names
+                            .into_iter()
+                            .filter(|name| name.name_id == id)
+                            .find_map(|name| name.to_string())
+                    })
+            })
+            .unwrap_or_else(|| {
+                file_name
+                    .rsplit_once('.')
+                    .map_or(&*file_name, |(stem, _)| stem)
+                    .to_string()
+            });
+        Self {
+            family: family.replace('"', "").into(),
+            file_name,
+            data,
+        }
+    }
+
+    /// The family quoted for use in `font-family`.
+    pub fn css_family(&self) -> String {
+        format!("\"{}\"", self.family)
+    }
+
+    fn to_record(&self) -> Result<Object, JsValue> {
+        let record = Object::new();
+        Reflect::set(&record, &"family".into(), &self.family.as_ref().into())?;
+        Reflect::set(&record, &"fileName".into(), &self.file_name.as_ref().into())?;
+        Reflect::set(&record, &"data".into(), &Uint8Array::from(&*self.data))?;
+        Ok(record)
+    }
+
+    fn from_record(record: &JsValue) -> Option<Self> {
+        let text = |key: &str| Reflect::get(record, &key.into()).ok()?.as_string();
+        let data = Reflect::get(record, &"data".into())
+            .ok()?
+            .dyn_into::<Uint8Array>()
+            .ok()?;
+        Some(Self {
+            family: text("family")?.into(),
+            file_name: text("fileName")?.into(),
+            data: data.to_vec().into(),
+        })
+    }
+}
+
+/// Waits for a request to succeed, giving its result.
+async fn settle(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let target = request.clone();
+        let onsuccess = Closure::once_into_js(move || {
+            let _ = resolve.call1(&JsValue::UNDEFINED, &target.result().unwrap_or_default());
+        });
+        let target = request.clone();
+        let onerror = Closure::once_into_js(move || {
+            let error = target.error().ok().flatten().map(JsValue::from);
+            let _ = reject.call1(&JsValue::UNDEFINED, &error.unwrap_or_default());
+        });
+        request.set_onsuccess(Some(onsuccess.unchecked_ref()));
+        request.set_onerror(Some(onerror.unchecked_ref()));
+    });
+    JsFuture::from(promise).await
+}
+
+async fn open() -> Result<IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .ok_or_else(|| JsValue::from_str("no window"))?
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("no IndexedDB"))?;
+    let request: IdbOpenDbRequest = factory.open_with_u32(DATABASE, 1)?;
+    let target = request.clone();
+    let onupgradeneeded = Closure::once_into_js(move || {
+        if let Some(database) = target
+            .result()
+            .ok()
+            .and_then(|result| result.dyn_into::<IdbDatabase>().ok())
+        {
+            if !database.object_store_names().contains(STORE) {
+                let _ = database.create_object_store(STORE);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(onupgradeneeded.unchecked_ref()));
+    settle(&request).await?.dyn_into()
+}
+
+async fn run(
+    mode: IdbTransactionMode,
+    action: impl FnOnce(&web_sys::IdbObjectStore) -> Result<IdbRequest, JsValue>,
+) -> Result<JsValue, JsValue> {
+    let database = open().await?;
+    let store = database
+        .transaction_with_str_and_mode(STORE, mode)?
+        .object_store(STORE)?;
+    let result = settle(&action(&store)?).await;
+    database.close();
+    result
+}
+
+/// Every font kept so far.
+pub async fn load() -> Result<Vec<LocalFont>, JsValue> {
+    let records = run(IdbTransactionMode::Readonly, |store| store.get_all()).await?;
+    Ok(Array::from(&records)
+        .iter()
+        .filter_map(|record| LocalFont::from_record(&record))
+        .collect())
+}
+
+/// Keeps the font, replacing the one of the same family.
+pub async fn save(font: &LocalFont) -> Result<(), JsValue> {
+    let record = font.to_record()?;
+    let family = JsValue::from_str(&font.family);
+    run(IdbTransactionMode::Readwrite, |store| {
+        store.put_with_key(&record, &family)
+    })
+    .await
+    .map(drop)
+}
+
+pub async fn delete(family: &str) -> Result<(), JsValue> {
+    let family = JsValue::from_str(family);
+    run(IdbTransactionMode::Readwrite, |store| store.delete(&family))
+        .await
+        .map(drop)
+}
+
+/// Makes the font usable by its family, failing if the browser cannot read it.
+pub async fn register(font: &LocalFont) -> Result<(), JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let face = FontFace::new_with_u8_array(&font.family, &font.data)?;
+    JsFuture::from(face.load()?).await?;
+    unregister(&font.family);
+    document.fonts().add(&face)?;
+    FACES.with_borrow_mut(|faces| faces.insert(font.family.clone(), face));
+    Ok(())
+}
+
+pub fn unregister(family: &str) {
+    let Some(face) = FACES.with_borrow_mut(|faces| faces.remove(family)) else {
+        return;
+    };
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        document.fonts().delete(&face);
+    }
+}
+
+/// Families installed on the device, where the browser can list them after asking the user.
+pub async fn installed_families() -> Result<Vec<Rc<str>>, JsValue> {
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let query = Reflect::get(&window, &"queryLocalFonts".into())?
+        .dyn_into::<Function>()
+        .map_err(|_| JsValue::from_str("cannot list installed fonts"))?;
+    let fonts = JsFuture::from(query.call0(&window)?.dyn_into::<Promise>()?).await?;
+    let mut families = Array::from(&fonts)
+        .iter()
+        .filter_map(|font| Reflect::get(&font, &"family".into()).ok()?.as_string())
+        .map(Rc::from)
+        .collect::<Vec<_>>();
+    families.sort();
+    families.dedup();
+    Ok(families)
+}
+
+/// Whether the browser can list the installed families with [`installed_families`].
+pub fn can_list_installed() -> bool {
+    cfg!(target_arch = "wasm32")
+        && web_sys::window()
+            .is_some_and(|window| Reflect::has(&window, &"queryLocalFonts".into()).unwrap_or(false))
+}
@@ -1,9 +1,15 @@
-mod app;
-mod form;
-mod translations;
-
-use app::App;
+use calendar::{app::App, prerender, service_worker};
+use yew::Renderer;
 
 fn main() {
-    yew::Renderer::<App>::new().render();
+    match prerender::root() {
+        Some(root) => {
+            let props = prerender::props(&root);
+            Renderer::<App>::with_root_and_props(root.into(), props).hydrate();
+        }
+        None => {
+            Renderer::<App>::new().render();
+        }
+    }
+    service_worker::register();
 }
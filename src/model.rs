@@ -0,0 +1,512 @@
+//! What the calendar shows, apart from how it is drawn.
+//!
+//! Nothing here depends on the browser, so the page, the exports and other front ends
+//! can share it.
+
+use std::{
+    fmt::{Formatter, Result as FmtResult},
+    iter::successors,
+};
+
+use chrono::{DateTime, Datelike, Month, Months, NaiveDate, Utc, Weekday};
+use nongli::{
+    calendar::{Calendar, Cell, Options},
+    is_weekend,
+    iter::Weekdays,
+    language::{Language, ShortTranslate, StaticTranslate, Translate},
+    SolarTerm,
+};
+
+use crate::{
+    astronomy,
+    fonts::Fonts,
+    palette::Palette,
+    time_zone::{DisplayZone, MonthEvents},
+    translations,
+};
+
+/// Months printed at most at once, to keep the page from growing without bound.
+pub const MAX_PRINTED_MONTHS: usize = 120;
+
+/// Why a range of months is not printed as it was chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    /// The last month comes before the first, so nothing is printed.
+    Reversed,
+    /// The range has this many months, more than [`MAX_PRINTED_MONTHS`], so only the first
+    /// ones are printed.
+    TooLong(i64),
+}
+
+impl Translate for RangeError {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match (self, language) {
+            (Self::Reversed, _) => translations::ReversedRange.translate(language, f),
+            (Self::TooLong(months), Language::English) => write!(
+                f,
+                "Only the first {MAX_PRINTED_MONTHS} of the {months} months are printed.",
+            ),
+            (Self::TooLong(months), Language::ChineseSimplified) => {
+                write!(f, "只打印 {months} 个月中的前 {MAX_PRINTED_MONTHS} 个月。")
+            }
+            (Self::TooLong(months), Language::ChineseTraditional) => {
+                write!(f, "只列印 {months} 個月中的前 {MAX_PRINTED_MONTHS} 個月。")
+            }
+        }
+    }
+}
+
+/// Checks that [`month_range`] gives every month from `from` to `to`.
+pub fn check_range(from: (i32, Month), to: (i32, Month)) -> Result<(), RangeError> {
+    let months = (to.0 as i64 - from.0 as i64) * 12 + to.1.number_from_month() as i64
+        - from.1.number_from_month() as i64
+        + 1;
+    if months < 1 {
+        Err(RangeError::Reversed)
+    } else if months > MAX_PRINTED_MONTHS as i64 {
+        Err(RangeError::TooLong(months))
+    } else {
+        Ok(())
+    }
+}
+
+/// Months from `from` to `to` inclusive, at most [`MAX_PRINTED_MONTHS`] of them;
+/// [`check_range`] tells when that is not all of them.
+pub fn month_range(from: (i32, Month), to: (i32, Month)) -> Vec<(i32, Month)> {
+    let Some(first) = NaiveDate::from_ymd_opt(from.0, from.1.number_from_month(), 1) else {
+        return Vec::new();
+    };
+    successors(Some(first), |date| date.checked_add_months(Months::new(1)))
+        .map(|date| (date.year(), Month::try_from(date.month() as u8).unwrap()))
+        .take_while(|(year, month)| {
+            (*year, month.number_from_month()) <= (to.0, to.1.number_from_month())
+        })
+        .take(MAX_PRINTED_MONTHS)
+        .collect()
+}
+
+pub fn solar_term_time(
+    date: NaiveDate,
+    solar_term: SolarTerm,
+    zone: DisplayZone,
+) -> Option<String> {
+    astronomy::solar_term_instant(date.year(), solar_term).map(|instant| zone.format(instant))
+}
+
+/// The custom properties of the palette and the fonts, for the `:root` rule.
+pub fn root_variables(palette: &Palette, fonts: &Fonts) -> String {
+    format!("{palette}{fonts}")
+}
+
+/// The title above the grid, such as "January 2025 一月".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub year: i32,
+    pub month: Month,
+    /// The name of the month in the language, if it is not English.
+    pub translated_month: Option<String>,
+}
+
+impl Header {
+    pub fn new(year: i32, month: Month, language: Language) -> Self {
+        Self {
+            year,
+            month,
+            translated_month: (language != Language::English)
+                .then(|| month.translate_to_string(language)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekdayHeading {
+    pub short: String,
+    pub full: String,
+    pub weekend: bool,
+}
+
+/// What the line below the day number is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChineseKind {
+    Date,
+    Festival,
+    SolarTerm,
+}
+
+impl ChineseKind {
+    /// Class of the line besides `chinese`.
+    pub fn class(self) -> Option<&'static str> {
+        match self {
+            Self::Date => None,
+            Self::Festival => Some("festival"),
+            Self::SolarTerm => Some("solar-term"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Day {
+    pub date: NaiveDate,
+    /// Today, and highlighted.
+    pub today: bool,
+    /// Today, whether highlighted or not.
+    pub current: bool,
+    pub weekend: bool,
+    pub new_moon: Option<DateTime<Utc>>,
+    /// A festival, a solar term or the Chinese date, if the Chinese calendar is shown.
+    pub chinese: Option<(String, ChineseKind)>,
+    /// The time of the solar term or the new moon on the day.
+    pub title: Option<String>,
+    /// What screen readers announce: the full date, then the Chinese date and events.
+    pub label: String,
+    has_info: bool,
+}
+
+impl Day {
+    /// Whether the day has a festival, solar term or new moon to tell more about.
+    pub fn has_info(&self) -> bool {
+        self.has_info
+    }
+
+    fn new(
+        cell: Cell,
+        new_moon: Option<DateTime<Utc>>,
+        today: NaiveDate,
+        options: &Options,
+        zone: DisplayZone,
+    ) -> Self {
+        let language = options.language;
+        let chinese = if let Some(festival) = cell.festival {
+            Some((
+                festival.static_translate(language).to_string(),
+                ChineseKind::Festival,
+            ))
+        } else if let Some(solar_term) = cell.solar_term {
+            Some((
+                solar_term.static_translate(language).to_string(),
+                ChineseKind::SolarTerm,
+            ))
+        } else {
+            cell.chinese_date.map(|chinese| {
+                (
+                    chinese.short().translate_to_string(language),
+                    ChineseKind::Date,
+                )
+            })
+        };
+        let title = cell
+            .solar_term
+            .and_then(|solar_term| solar_term_time(cell.date, solar_term, zone))
+            .or_else(|| {
+                new_moon.map(|instant| {
+                    format!(
+                        "{}: {}",
+                        translations::NewMoon.static_translate(language),
+                        zone.format(instant),
+                    )
+                })
+            });
+
+        let mut label = vec![translations::FullDate(cell.date).translate_to_string(language)];
+        if let Some(chinese_date) = cell.chinese_date {
+            label.push(chinese_date.translate_to_string(language));
+        }
+        if let Some(festival) = cell.festival {
+            label.push(festival.static_translate(language).to_string());
+        }
+        if let Some(solar_term) = cell.solar_term {
+            label.push(solar_term.static_translate(language).to_string());
+        }
+        if new_moon.is_some() {
+            label.push(translations::NewMoon.static_translate(language).to_string());
+        }
+
+        Self {
+            date: cell.date,
+            today: cell.today,
+            current: cell.date == today,
+            weekend: cell.weekend,
+            new_moon,
+            chinese,
+            title,
+            label: label.join(if language == Language::English {
+                ", "
+            } else {
+                "，"
+            }),
+            has_info: cell.festival.is_some() || cell.solar_term.is_some() || new_moon.is_some(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Week {
+    pub number: u32,
+    /// Such as "Week 2".
+    pub label: String,
+    pub days: [Option<Day>; 7],
+}
+
+/// A month as the page shows it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonthPage {
+    pub header: Header,
+    /// Such as "January 2025", for the grid.
+    pub title: String,
+    pub options: Options,
+    pub weekdays: Vec<WeekdayHeading>,
+    pub weeks: Vec<Week>,
+}
+
+impl MonthPage {
+    /// Today is highlighted if `highlight_today`, and solar terms and new moons fall on
+    /// the days they do in `zone`.
+    pub fn new(
+        year: i32,
+        month: Month,
+        today: NaiveDate,
+        highlight_today: bool,
+        options: Options,
+        zone: DisplayZone,
+    ) -> Option<Self> {
+        let calendar = Calendar::new(year, month, highlight_today.then_some(today), options)?;
+        let language = options.language;
+        let events = options
+            .enable_chinese
+            .then(|| MonthEvents::new(zone, year, month));
+        let start_of_week = if options.start_on_monday {
+            Weekday::Mon
+        } else {
+            Weekday::Sun
+        };
+        Some(Self {
+            header: Header::new(calendar.year(), month, language),
+            title: calendar.title().translate_to_string(language),
+            options,
+            weekdays: Weekdays(start_of_week)
+                .take(7)
+                .map(|weekday| WeekdayHeading {
+                    short: weekday.short().translate_to_string(language),
+                    full: weekday.translate_to_string(language),
+                    weekend: is_weekend(weekday),
+                })
+                .collect(),
+            weeks: calendar
+                .iter()
+                .map(|(number, row)| Week {
+                    number,
+                    label: translations::WeekNumber(number).translate_to_string(language),
+                    days: row.map(|cell| {
+                        cell.map(|cell| {
+                            let (cell, new_moon) = match &events {
+                                Some(events) => events.apply(cell),
+                                None => (cell, None),
+                            };
+                            Day::new(cell, new_moon, today, &options, zone)
+                        })
+                    }),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Tz;
+
+    use super::*;
+
+    const SHANGHAI: DisplayZone = DisplayZone(Some(Tz::Asia__Shanghai));
+
+    fn options(start_on_monday: bool) -> Options {
+        Options {
+            language: Language::ChineseSimplified,
+            enable_chinese: true,
+            start_on_monday,
+            week_number: true,
+            color: false,
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn page(year: i32, month: Month, start_on_monday: bool, zone: DisplayZone) -> MonthPage {
+        MonthPage::new(
+            year,
+            month,
+            date(2025, 1, 29),
+            true,
+            options(start_on_monday),
+            zone,
+        )
+        .unwrap()
+    }
+
+    fn day(page: &MonthPage, date: NaiveDate) -> &Day {
+        page.weeks
+            .iter()
+            .flat_map(|week| week.days.iter().flatten())
+            .find(|day| day.date == date)
+            .unwrap()
+    }
+
+    /// The days of each week, 0 for the blanks before and after the month.
+    fn layout(page: &MonthPage) -> Vec<[u32; 7]> {
+        page.weeks
+            .iter()
+            .map(|week| {
+                week.days
+                    .each_ref()
+                    .map(|day| day.as_ref().map_or(0, |day| day.date.day()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn leaves_blanks_around_the_month() {
+        let page = page(2025, Month::January, false, SHANGHAI);
+        assert_eq!(
+            layout(&page),
+            [
+                [0, 0, 0, 1, 2, 3, 4],
+                [5, 6, 7, 8, 9, 10, 11],
+                [12, 13, 14, 15, 16, 17, 18],
+                [19, 20, 21, 22, 23, 24, 25],
+                [26, 27, 28, 29, 30, 31, 0],
+            ],
+        );
+    }
+
+    #[test]
+    fn starts_the_week_on_monday() {
+        let sunday = page(2024, Month::December, false, SHANGHAI);
+        let monday = page(2024, Month::December, true, SHANGHAI);
+        assert_eq!(sunday.weekdays[0].short, "日");
+        assert!(sunday.weekdays[0].weekend);
+        assert_eq!(monday.weekdays[0].short, "一");
+        assert!(!monday.weekdays[0].weekend);
+        assert_eq!(layout(&sunday)[0], [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(layout(&monday)[0], [0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(layout(&monday).last().unwrap(), &[30, 31, 0, 0, 0, 0, 0]);
+        assert_eq!(monday.weeks.len(), 6);
+    }
+
+    #[test]
+    fn numbers_the_weeks() {
+        let numbers = |page: &MonthPage| {
+            page.weeks
+                .iter()
+                .map(|week| week.number)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            numbers(&page(2025, Month::January, false, SHANGHAI)),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            numbers(&page(2024, Month::December, true, SHANGHAI)),
+            [48, 49, 50, 51, 52, 1],
+        );
+    }
+
+    #[test]
+    fn labels_festivals_and_solar_terms() {
+        let page = page(2025, Month::January, false, SHANGHAI);
+        let new_year = day(&page, date(2025, 1, 1));
+        assert_eq!(
+            new_year.chinese,
+            Some(("初二".to_string(), ChineseKind::Date))
+        );
+        assert!(!new_year.has_info());
+
+        let spring_festival = day(&page, date(2025, 1, 29));
+        assert_eq!(
+            spring_festival.chinese,
+            Some(("春节".to_string(), ChineseKind::Festival)),
+        );
+        assert!(spring_festival.today && spring_festival.current);
+        assert!(spring_festival.new_moon.is_some());
+        assert!(
+            spring_festival.label.ends_with("，春节，新月"),
+            "{}",
+            spring_festival.label
+        );
+
+        let xiaohan = day(&page, date(2025, 1, 5));
+        assert_eq!(
+            xiaohan.chinese,
+            Some(("小寒".to_string(), ChineseKind::SolarTerm))
+        );
+        assert!(xiaohan.has_info());
+        assert!(xiaohan.title.as_deref().unwrap().starts_with("2025-01-05"));
+    }
+
+    #[test]
+    fn moves_solar_terms_to_the_day_in_the_zone() {
+        // The December solstice of 2024 is at 09:20 UTC, on the evening of the 21st in
+        // Shanghai but still on the 20th in Honolulu.
+        let dongzhi = Some(("冬至".to_string(), ChineseKind::SolarTerm));
+        let shanghai = page(2024, Month::December, false, SHANGHAI);
+        assert_eq!(day(&shanghai, date(2024, 12, 21)).chinese, dongzhi);
+        let honolulu = page(
+            2024,
+            Month::December,
+            false,
+            DisplayZone(Some(Tz::Pacific__Honolulu)),
+        );
+        assert_eq!(day(&honolulu, date(2024, 12, 20)).chinese, dongzhi);
+        assert_ne!(day(&honolulu, date(2024, 12, 21)).chinese, dongzhi);
+    }
+
+    #[test]
+    fn ranges_over_months() {
+        assert_eq!(
+            month_range((2024, Month::November), (2025, Month::February)),
+            [
+                (2024, Month::November),
+                (2024, Month::December),
+                (2025, Month::January),
+                (2025, Month::February),
+            ],
+        );
+        assert_eq!(
+            month_range((2025, Month::March), (2025, Month::March)),
+            [(2025, Month::March)],
+        );
+        assert!(month_range((2025, Month::March), (2025, Month::February)).is_empty());
+        let capped = month_range((2000, Month::January), (2100, Month::December));
+        assert_eq!(capped.len(), MAX_PRINTED_MONTHS);
+        assert_eq!(capped.last(), Some(&(2009, Month::December)));
+    }
+
+    #[test]
+    fn checks_ranges() {
+        assert_eq!(
+            check_range((2025, Month::March), (2025, Month::March)),
+            Ok(())
+        );
+        assert_eq!(
+            check_range((2000, Month::January), (2009, Month::December)),
+            Ok(()),
+        );
+        assert_eq!(
+            check_range((2025, Month::March), (2025, Month::February)),
+            Err(RangeError::Reversed),
+        );
+        assert_eq!(
+            check_range((2026, Month::January), (2025, Month::December)),
+            Err(RangeError::Reversed),
+        );
+        assert_eq!(
+            check_range((2000, Month::January), (2010, Month::January)),
+            Err(RangeError::TooLong(121)),
+        );
+        assert_eq!(
+            check_range((-262143, Month::January), (262142, Month::December)),
+            Err(RangeError::TooLong(524286 * 12)),
+        );
+    }
+}
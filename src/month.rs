@@ -0,0 +1,165 @@
+use chrono::{Datelike, Month, NaiveDate};
+use nongli::{calendar::Options, language::Language};
+use yew::prelude::*;
+
+use crate::{
+    model::{Header, MonthPage},
+    time_zone::DisplayZone,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct HeaderProps {
+    pub year: i32,
+    pub month: Month,
+    pub language: Language,
+}
+
+#[function_component]
+pub fn MonthHeader(props: &HeaderProps) -> Html {
+    let header = Header::new(props.year, props.month, props.language);
+    html! { <div class="header">
+        <div class="side left">{ header.month.name() }</div>
+        <div class="year">{ header.year }</div>
+        <div class="side right">
+            if let Some(translated_month) = header.translated_month {
+                { translated_month }
+            }
+        </div>
+    </div> }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct Props {
+    pub year: i32,
+    pub month: Month,
+    pub today: NaiveDate,
+    pub highlight_today: bool,
+    pub options: Options,
+    pub time_zone: DisplayZone,
+    #[prop_or_default]
+    pub class: Classes,
+    /// The cell reachable with Tab; the grid is not focusable if `None`.
+    #[prop_or_default]
+    pub focus_target: Option<NaiveDate>,
+    /// Called when a day with a festival, solar term or new moon is clicked.
+    #[prop_or_default]
+    pub onselect: Option<Callback<NaiveDate>>,
+}
+
+/// The grid of days of a month.
+#[function_component]
+pub fn MonthTable(props: &Props) -> Html {
+    let page = use_memo(
+        (
+            props.year,
+            props.month,
+            props.today,
+            props.highlight_today,
+            props.options,
+            props.time_zone,
+        ),
+        |(year, month, today, highlight_today, options, time_zone)| {
+            MonthPage::new(
+                *year,
+                *month,
+                *today,
+                *highlight_today,
+                *options,
+                *time_zone,
+            )
+            .unwrap()
+        },
+    );
+    let Options {
+        enable_chinese,
+        week_number: show_week_numbers,
+        ..
+    } = page.options;
+    html! { <table
+        class={ classes!("calendar", props.class.clone()) }
+        role="grid"
+        aria-label={ page.title.clone() }
+    >
+        <tr role="row">
+            if show_week_numbers { <th role="columnheader"></th> }
+            {
+                for page.weekdays.iter().map(|weekday| html! {
+                    <th
+                        role="columnheader"
+                        abbr={ weekday.full.clone() }
+                        class={classes!(
+                            "weekday",
+                            weekday.weekend.then_some("weekend"),
+                        )}
+                    >
+                        { &weekday.short }
+                    </th>
+                })
+            }
+        </tr>
+        {
+            for page.weeks.iter().map(|week| html! {
+                <tr role="row">
+                    if show_week_numbers {
+                        <th
+                            role="rowheader"
+                            class="week-number"
+                            aria-label={ week.label.clone() }
+                        >
+                            { week.number }
+                        </th>
+                    }
+                    {
+                        for week.days.iter().map(|day| html! {
+                            if let Some(day) = day {
+                                <td
+                                    role="gridcell"
+                                    aria-label={ day.label.clone() }
+                                    aria-current={ day.current.then_some("date") }
+                                    aria-haspopup={
+                                        (props.onselect.is_some() && day.has_info())
+                                            .then_some("dialog")
+                                    }
+                                    data-date={ props.focus_target.is_some().then(|| day.date.to_string()) }
+                                    tabindex={
+                                        props.focus_target.map(|target| {
+                                            if day.date == target { "0" } else { "-1" }
+                                        })
+                                    }
+                                    class={ classes!(
+                                        day.today.then_some("today"),
+                                        day.weekend.then_some("weekend"),
+                                        (props.onselect.is_some() && day.has_info())
+                                            .then_some("has-info"),
+                                        day.new_moon.is_some().then_some("new-moon"),
+                                    ) }
+                                    title={ day.title.clone() }
+                                    onclick={
+                                        day.has_info()
+                                            .then_some(props.onselect.clone())
+                                            .flatten()
+                                            .map(|onselect| {
+                                                let date = day.date;
+                                                Callback::from(move |_| onselect.emit(date))
+                                            })
+                                    }
+                                >
+                                    <div class="day">{ day.date.day() }</div>
+                                    if let Some((text, kind)) = &day.chinese {
+                                        <div class={ classes!("chinese", kind.class()) }>
+                                            { text }
+                                        </div>
+                                    } else if enable_chinese {
+                                        <div class="chinese"></div>
+                                    }
+                                </td>
+                            } else {
+                                <td role="gridcell"></td>
+                            }
+                        })
+                    }
+                </tr>
+            })
+        }
+    </table> }
+}
@@ -0,0 +1,45 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    rc::Rc,
+};
+
+use crate::roles::roles;
+
+roles! {
+    pub enum ColorRole {
+        Text => "--color-text", TextColor;
+        Theme => "--color-theme", ThemeColor;
+        Year => "--color-year", YearColor;
+        Month => "--color-month", MonthColor;
+        TodayText => "--color-today-text", TodayTextColor;
+        Weekend => "--color-weekend", WeekendColor;
+        WeekNumber => "--color-week-number", WeekNumberColor;
+        Festival => "--color-festival", FestivalColor;
+        SolarTerm => "--color-solar-term", SolarTermColor;
+    }
+    /// Values of all colors, indexed by [`ColorRole`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Palette;
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self(
+            [
+                "#111111", "#0000ff", "#808080", "#808080", "#ffffff", "#ff0000", "#808080",
+                "inherit", "inherit",
+            ]
+            .map(Rc::from),
+        )
+    }
+}
+
+/// A `--color-*: value;` line for each role, as the body of a CSS rule.
+impl Display for Palette {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        for role in ColorRole::ALL {
+            writeln!(f, "{}: {};", role.variable(), self[role])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+//! Pages rendered ahead of time, which the app takes over when it loads.
+//!
+//! The settings a page was rendered with are kept in `data-*` attributes of its root element,
+//! so that the app starts with the same ones.
+
+use chrono::Month;
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+
+use crate::{app::Props, translations};
+
+/// ID of the element holding a pre-rendered page.
+pub const ROOT_ID: &str = "calendar";
+
+/// Attributes of the root element describing the settings.
+pub fn attributes(props: &Props) -> String {
+    let mut attributes = format!(
+        "id=\"{ROOT_ID}\" data-language=\"{}\"",
+        translations::language_tag(props.language)
+    );
+    if let Some((year, month)) = props.month {
+        attributes.push_str(&format!(
+            " data-month=\"{year}-{:02}\"",
+            month.number_from_month()
+        ));
+    }
+    for (name, value) in [
+        ("chinese", props.enable_chinese),
+        ("monday", props.start_on_monday),
+        ("week-numbers", props.show_week_numbers),
+        ("no-highlight-today", !props.highlight_today),
+    ] {
+        if value {
+            attributes.push_str(&format!(" data-{name}"));
+        }
+    }
+    attributes
+}
+
+/// The root element of the pre-rendered page, if the page is one.
+pub fn root() -> Option<HtmlElement> {
+    let document = web_sys::window()?.document()?;
+    document
+        .get_element_by_id(ROOT_ID)
+        .and_then(|root| root.dyn_into().ok())
+}
+
+/// The settings the page was rendered with, read back from [`attributes`].
+pub fn props(root: &Element) -> Props {
+    let month = root.get_attribute("data-month").and_then(|month| {
+        let (year, month) = month.rsplit_once('-')?;
+        Some((
+            year.parse().ok()?,
+            Month::try_from(month.parse::<u8>().ok()?).ok()?,
+        ))
+    });
+    Props {
+        month,
+        language: root
+            .get_attribute("data-language")
+            .and_then(|tag| translations::language_from_tag(&tag))
+            .unwrap_or(Props::default().language),
+        enable_chinese: root.has_attribute("data-chinese"),
+        start_on_monday: root.has_attribute("data-monday"),
+        show_week_numbers: root.has_attribute("data-week-numbers"),
+        highlight_today: !root.has_attribute("data-no-highlight-today"),
+    }
+}
@@ -0,0 +1,171 @@
+use std::rc::Rc;
+
+use nongli::language::{Language, StaticTranslate};
+use yew::Reducible;
+
+use crate::{
+    palette::{ColorRole, Palette},
+    translations,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Paper {
+    A4,
+    A3,
+    Letter,
+    Legal,
+    Custom,
+}
+
+impl Paper {
+    pub const ALL: [Self; 5] = [Self::A4, Self::A3, Self::Letter, Self::Legal, Self::Custom];
+
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Width and height in millimeters, in portrait orientation.
+    pub fn size(self) -> Option<(f64, f64)> {
+        match self {
+            Self::A4 => Some((210.0, 297.0)),
+            Self::A3 => Some((297.0, 420.0)),
+            Self::Letter => Some((215.9, 279.4)),
+            Self::Legal => Some((215.9, 355.6)),
+            Self::Custom => None,
+        }
+    }
+}
+
+impl StaticTranslate for Paper {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::A4 => "A4",
+            Self::A3 => "A3",
+            Self::Letter => "Letter",
+            Self::Legal => "Legal",
+            Self::Custom => translations::Custom.static_translate(language),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+impl Orientation {
+    pub const ALL: [Self; 2] = [Self::Portrait, Self::Landscape];
+
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+}
+
+impl StaticTranslate for Orientation {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::Portrait => translations::Portrait.static_translate(language),
+            Self::Landscape => translations::Landscape.static_translate(language),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintSetup {
+    pub paper: Paper,
+    /// Width of custom paper in millimeters.
+    pub width: i32,
+    /// Height of custom paper in millimeters.
+    pub height: i32,
+    pub orientation: Orientation,
+    /// Margin on each side in millimeters.
+    pub margin: i32,
+    /// Stretch the month to fill exactly one page.
+    pub scale_to_fit: bool,
+    /// Colors used instead of the ones on the screen when printing.
+    pub palette: Option<Palette>,
+}
+
+impl Default for PrintSetup {
+    fn default() -> Self {
+        Self {
+            paper: Paper::A4,
+            width: 210,
+            height: 297,
+            orientation: Orientation::Portrait,
+            margin: 10,
+            scale_to_fit: true,
+            palette: None,
+        }
+    }
+}
+
+pub enum PrintSetupAction {
+    Paper(Paper),
+    Width(i32),
+    Height(i32),
+    Orientation(Orientation),
+    Margin(i32),
+    ScaleToFit(bool),
+    /// Use separate colors, starting from the given ones, or stop using them.
+    Palette(Option<Palette>),
+    Color(ColorRole, Rc<str>),
+}
+
+impl Reducible for PrintSetup {
+    type Action = PrintSetupAction;
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut setup = self.as_ref().clone();
+        match action {
+            PrintSetupAction::Paper(paper) => setup.paper = paper,
+            PrintSetupAction::Width(width) => setup.width = width,
+            PrintSetupAction::Height(height) => setup.height = height,
+            PrintSetupAction::Orientation(orientation) => setup.orientation = orientation,
+            PrintSetupAction::Margin(margin) => setup.margin = margin,
+            PrintSetupAction::ScaleToFit(scale_to_fit) => setup.scale_to_fit = scale_to_fit,
+            PrintSetupAction::Palette(palette) => setup.palette = palette,
+            PrintSetupAction::Color(role, value) => {
+                if let Some(palette) = &mut setup.palette {
+                    palette[role] = value;
+                }
+            }
+        }
+        Rc::new(setup)
+    }
+}
+
+impl PrintSetup {
+    /// Width and height of the page in millimeters, in the chosen orientation.
+    pub fn page_size(&self) -> (f64, f64) {
+        let (width, height) = self
+            .paper
+            .size()
+            .unwrap_or((self.width.max(1) as f64, self.height.max(1) as f64));
+        match self.orientation {
+            Orientation::Portrait => (width, height),
+            Orientation::Landscape => (height, width),
+        }
+    }
+
+    /// Width and height of the area inside the margins in millimeters.
+    pub fn printable_size(&self) -> (f64, f64) {
+        let (width, height) = self.page_size();
+        let margin = self.margin.max(0) as f64 * 2.0;
+        ((width - margin).max(1.0), (height - margin).max(1.0))
+    }
+
+    /// The `@page` rule, and the variables and colors used when printing.
+    pub fn css(&self) -> String {
+        let (page_width, page_height) = self.page_size();
+        let (width, height) = self.printable_size();
+        format!(
+            "@page {{\nsize: {page_width}mm {page_height}mm;\nmargin: {}mm;\n}}\n\
+            @media print {{\n:root {{\n--print-width: {width}mm;\n--print-height: {height}mm;\n{}}}\n}}\n",
+            self.margin.max(0),
+            self.palette
+                .as_ref()
+                .map_or_else(String::new, |palette| palette.to_string()),
+        )
+    }
+}
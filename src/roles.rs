@@ -0,0 +1,69 @@
+/// Defines the roles of a kind of style, each with its CSS variable and its name in the Styles
+/// dialog, and the newtype holding a value for each role.
+///
+/// ```ignore
+/// roles! {
+///     pub enum ColorRole {
+///         Text => "--color-text", TextColor;
+///     }
+///     #[derive(Clone, Debug, PartialEq, Eq)]
+///     pub struct Palette;
+/// }
+/// ```
+macro_rules! roles {
+    (
+        $(#[$role_meta: meta])*
+        pub enum $role: ident {
+            $($variant: ident => $variable: literal, $translation: ident;)*
+        }
+        $(#[$values_meta: meta])*
+        pub struct $values: ident;
+    ) => {
+        $(#[$role_meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $role {
+            $($variant,)*
+        }
+
+        impl $role {
+            /// In the order they appear in the Styles dialog.
+            pub const ALL: [Self; [$(stringify!($variant)),*].len()] = [$(Self::$variant),*];
+
+            /// The CSS variable holding the value of the role.
+            pub fn variable(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $variable,)*
+                }
+            }
+        }
+
+        impl ::nongli::language::StaticTranslate for $role {
+            fn static_translate(&self, language: ::nongli::language::Language) -> &'static str {
+                match self {
+                    $(Self::$variant => ::nongli::language::StaticTranslate::static_translate(
+                        &$crate::translations::$translation,
+                        language,
+                    ),)*
+                }
+            }
+        }
+
+        $(#[$values_meta])*
+        pub struct $values([::std::rc::Rc<str>; $role::ALL.len()]);
+
+        impl ::std::ops::Index<$role> for $values {
+            type Output = ::std::rc::Rc<str>;
+            fn index(&self, role: $role) -> &::std::rc::Rc<str> {
+                &self.0[role as usize]
+            }
+        }
+
+        impl ::std::ops::IndexMut<$role> for $values {
+            fn index_mut(&mut self, role: $role) -> &mut ::std::rc::Rc<str> {
+                &mut self.0[role as usize]
+            }
+        }
+    };
+}
+
+pub(crate) use roles;
@@ -0,0 +1,15 @@
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+/// Registers the service worker which keeps the app and its fonts for use offline.
+///
+/// Does nothing where service workers are not available, such as on plain HTTP.
+pub fn register() {
+    let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+        return;
+    };
+    if Reflect::has(&navigator, &JsValue::from_str("serviceWorker")) != Ok(true) {
+        return;
+    }
+    let _ = navigator.service_worker().register("service-worker.js");
+}
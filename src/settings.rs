@@ -0,0 +1,284 @@
+//! What the user has chosen, kept in one place and shared with the dialogs.
+
+use std::{ops::Deref, rc::Rc};
+
+use chrono::Month;
+use nongli::{calendar::Options, language::Language};
+use yew::prelude::*;
+
+use crate::{
+    export::{self, png},
+    fonts::{FontRole, Fonts},
+    history::{History, HistoryAction, Undoable},
+    palette::{ColorRole, Palette},
+    print::{PrintSetup, PrintSetupAction},
+    sizes::{Breakpoint, ResponsiveSizes, Size},
+    time_zone::DisplayZone,
+};
+
+/// Languages in the order the Settings dialog offers them.
+pub const LANGUAGES: &[Language] = &[
+    Language::English,
+    Language::ChineseSimplified,
+    Language::ChineseTraditional,
+];
+
+/// The look of the calendar, set in the Styles dialog.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Styles {
+    pub palette: Palette,
+    pub fonts: Fonts,
+    /// Whether families not on the device are loaded from Google Fonts, off until turned on.
+    pub download_fonts: bool,
+    pub sizes: ResponsiveSizes,
+}
+
+/// A field of the Styles dialog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StyleField {
+    Color(ColorRole),
+    Font(FontRole),
+    DownloadFonts,
+    Size(Breakpoint, Size),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StylesAction {
+    Color(ColorRole, Rc<str>),
+    Font(FontRole, Rc<str>),
+    DownloadFonts(bool),
+    Size(Breakpoint, Size, Rc<str>),
+    /// Puts a field back to its default value.
+    Reset(StyleField),
+    ResetAll,
+}
+
+impl Styles {
+    pub fn is_default(&self, field: StyleField) -> bool {
+        let defaults = Self::default();
+        match field {
+            StyleField::Color(role) => self.palette[role] == defaults.palette[role],
+            StyleField::Font(role) => self.fonts[role] == defaults.fonts[role],
+            StyleField::DownloadFonts => self.download_fonts == defaults.download_fonts,
+            StyleField::Size(breakpoint, size) => {
+                self.sizes[breakpoint][size] == defaults.sizes[breakpoint][size]
+            }
+        }
+    }
+}
+
+impl Undoable for Styles {
+    type Change = StylesAction;
+    type Field = StyleField;
+
+    fn field(change: &StylesAction) -> Option<StyleField> {
+        match change {
+            StylesAction::Color(role, _) => Some(StyleField::Color(*role)),
+            StylesAction::Font(role, _) => Some(StyleField::Font(*role)),
+            StylesAction::DownloadFonts(_) => Some(StyleField::DownloadFonts),
+            StylesAction::Size(breakpoint, size, _) => Some(StyleField::Size(*breakpoint, *size)),
+            StylesAction::Reset(_) | StylesAction::ResetAll => None,
+        }
+    }
+
+    fn apply(&self, change: StylesAction) -> Self {
+        let mut styles = self.clone();
+        match change {
+            StylesAction::Color(role, value) => styles.palette[role] = value,
+            StylesAction::Font(role, value) => styles.fonts[role] = value,
+            StylesAction::DownloadFonts(download_fonts) => styles.download_fonts = download_fonts,
+            StylesAction::Size(breakpoint, size, value) => styles.sizes[breakpoint][size] = value,
+            StylesAction::Reset(field) => {
+                let defaults = Self::default();
+                match field {
+                    StyleField::Color(role) => {
+                        styles.palette[role] = defaults.palette[role].clone()
+                    }
+                    StyleField::Font(role) => styles.fonts[role] = defaults.fonts[role].clone(),
+                    StyleField::DownloadFonts => styles.download_fonts = defaults.download_fonts,
+                    StyleField::Size(breakpoint, size) => {
+                        styles.sizes[breakpoint][size] = defaults.sizes[breakpoint][size].clone()
+                    }
+                }
+            }
+            StylesAction::ResetAll => styles = Self::default(),
+        }
+        styles
+    }
+}
+
+/// What the Settings, Styles and Print dialogs set, which can be undone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preferences {
+    pub language: Language,
+    pub enable_chinese: bool,
+    pub start_on_monday: bool,
+    pub show_week_numbers: bool,
+    pub highlight_today: bool,
+    /// Move to the new month when the current one ends.
+    pub auto_advance: bool,
+    pub wheel_navigation: bool,
+    pub time_zone: DisplayZone,
+    pub styles: Styles,
+    pub print_setup: PrintSetup,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            language: Language::English,
+            enable_chinese: false,
+            start_on_monday: false,
+            show_week_numbers: false,
+            highlight_today: true,
+            auto_advance: false,
+            wheel_navigation: false,
+            time_zone: DisplayZone(None),
+            styles: Styles::default(),
+            print_setup: PrintSetup::default(),
+        }
+    }
+}
+
+/// A field of the preferences which takes continuous input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreferenceField {
+    Style(StyleField),
+    PrintColor(ColorRole),
+}
+
+pub enum PreferencesAction {
+    Language(Language),
+    EnableChinese(bool),
+    StartOnMonday(bool),
+    ShowWeekNumbers(bool),
+    HighlightToday(bool),
+    AutoAdvance(bool),
+    WheelNavigation(bool),
+    TimeZone(DisplayZone),
+    Styles(StylesAction),
+    PrintSetup(PrintSetupAction),
+}
+
+impl Undoable for Preferences {
+    type Change = PreferencesAction;
+    type Field = PreferenceField;
+
+    fn field(change: &PreferencesAction) -> Option<PreferenceField> {
+        match change {
+            PreferencesAction::Styles(action) => Styles::field(action).map(PreferenceField::Style),
+            PreferencesAction::PrintSetup(PrintSetupAction::Color(role, _)) => {
+                Some(PreferenceField::PrintColor(*role))
+            }
+            _ => None,
+        }
+    }
+
+    fn apply(&self, change: PreferencesAction) -> Self {
+        let mut preferences = self.clone();
+        match change {
+            PreferencesAction::Language(language) => preferences.language = language,
+            PreferencesAction::EnableChinese(enable) => preferences.enable_chinese = enable,
+            PreferencesAction::StartOnMonday(start) => preferences.start_on_monday = start,
+            PreferencesAction::ShowWeekNumbers(show) => preferences.show_week_numbers = show,
+            PreferencesAction::HighlightToday(highlight) => preferences.highlight_today = highlight,
+            PreferencesAction::AutoAdvance(auto_advance) => preferences.auto_advance = auto_advance,
+            PreferencesAction::WheelNavigation(enable) => preferences.wheel_navigation = enable,
+            PreferencesAction::TimeZone(time_zone) => preferences.time_zone = time_zone,
+            PreferencesAction::Styles(action) => preferences.styles = self.styles.apply(action),
+            PreferencesAction::PrintSetup(action) => {
+                preferences.print_setup =
+                    Rc::unwrap_or_clone(Rc::new(preferences.print_setup).reduce(action))
+            }
+        }
+        preferences
+    }
+}
+
+/// Everything set in the dialogs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settings {
+    pub preferences: History<Preferences>,
+    /// The first and the last month printed by Print Range.
+    pub print_range: ((i32, Month), (i32, Month)),
+    pub export_months: export::Months,
+    pub image_dpi: i32,
+    /// Name and data of the font embedded into PDFs.
+    pub pdf_font: Option<(Rc<str>, Rc<[u8]>)>,
+    /// The face embedded when the PDF font is a collection.
+    pub pdf_face: u32,
+}
+
+impl Settings {
+    /// The defaults, with the print range covering `year`.
+    pub fn new(year: i32) -> Self {
+        Self {
+            preferences: History::new(Preferences::default()),
+            print_range: ((year, Month::January), (year, Month::December)),
+            export_months: export::Months::Current,
+            image_dpi: png::CSS_DPI * 2,
+            pdf_font: None,
+            pdf_face: 0,
+        }
+    }
+
+    /// Options of the calendar grid.
+    pub fn options(&self) -> Options {
+        Options {
+            language: self.language,
+            enable_chinese: self.enable_chinese,
+            start_on_monday: self.start_on_monday,
+            week_number: self.show_week_numbers,
+            color: false,
+        }
+    }
+}
+
+impl Deref for Settings {
+    type Target = Preferences;
+    fn deref(&self) -> &Preferences {
+        &self.preferences
+    }
+}
+
+pub enum SettingsAction {
+    Preferences(HistoryAction<PreferencesAction>),
+    PrintFrom(i32, Month),
+    PrintTo(i32, Month),
+    ExportMonths(export::Months),
+    ImageDpi(i32),
+    PdfFont(Rc<str>, Rc<[u8]>),
+    PdfFace(u32),
+}
+
+impl Reducible for Settings {
+    type Action = SettingsAction;
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut settings = Rc::unwrap_or_clone(self);
+        match action {
+            SettingsAction::Preferences(action) => {
+                settings.preferences =
+                    Rc::unwrap_or_clone(Rc::new(settings.preferences).reduce(action))
+            }
+            SettingsAction::PrintFrom(year, month) => settings.print_range.0 = (year, month),
+            SettingsAction::PrintTo(year, month) => settings.print_range.1 = (year, month),
+            SettingsAction::ExportMonths(months) => settings.export_months = months,
+            SettingsAction::ImageDpi(dpi) => settings.image_dpi = dpi,
+            SettingsAction::PdfFont(name, data) => {
+                settings.pdf_font = Some((name, data));
+                settings.pdf_face = 0;
+            }
+            SettingsAction::PdfFace(index) => settings.pdf_face = index,
+        }
+        Rc::new(settings)
+    }
+}
+
+/// The settings with their dispatcher, provided by the app to the components inside it.
+pub type SettingsContext = UseReducerHandle<Settings>;
+
+/// The settings provided by the app.
+#[hook]
+pub fn use_settings() -> SettingsContext {
+    use_context::<SettingsContext>().expect("settings are provided by the app")
+}
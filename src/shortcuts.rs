@@ -0,0 +1,123 @@
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use nongli::language::StaticTranslate;
+use web_sys::{wasm_bindgen::JsCast, Element, KeyboardEvent};
+
+use crate::translations;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shortcut {
+    PrevMonth,
+    NextMonth,
+    PrevYear,
+    NextYear,
+    Today,
+    Jump,
+    Styles,
+    Settings,
+    Print,
+    Close,
+    Help,
+    /// Undo the last change of the preferences.
+    Undo,
+    Redo,
+    /// Move the focus to the cell of another day.
+    Focus(NaiveDate),
+    /// Open the details of the focused day.
+    Activate,
+}
+
+/// Keys and descriptions listed in the help overlay.
+pub const HELP: &[(&[&str], &dyn StaticTranslate)] = &[
+    (&["←", "PageUp"], &translations::PrevMonth),
+    (&["→", "PageDown"], &translations::NextMonth),
+    (&["Shift+←", "Shift+PageUp"], &translations::PrevYear),
+    (&["Shift+→", "Shift+PageDown"], &translations::NextYear),
+    (&["T"], &translations::Today),
+    (&["J"], &translations::Jump),
+    (&["S"], &translations::Styles),
+    (&[","], &translations::Settings),
+    (&["P"], &translations::Print),
+    (&["Esc"], &translations::Close),
+    (&["Ctrl+Z"], &translations::Undo),
+    (&["Ctrl+Shift+Z", "Ctrl+Y"], &translations::Redo),
+    (
+        &["←", "→", "↑", "↓", "Home", "End"],
+        &translations::MoveBetweenDays,
+    ),
+    (&["Enter"], &translations::ShowDetails),
+    (&["?"], &translations::KeyboardShortcuts),
+];
+
+impl Shortcut {
+    /// The shortcut pressed, `start_of_week` being the first weekday of each row.
+    pub fn from_event(event: &KeyboardEvent, start_of_week: Weekday) -> Option<Self> {
+        use Shortcut::*;
+        let target = event
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok());
+        if (event.ctrl_key() || event.meta_key()) && !event.alt_key() {
+            // Text fields undo their own typing.
+            if target.as_ref().is_some_and(|target| {
+                matches!(target.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA")
+            }) {
+                return None;
+            }
+            return match event.key().as_str() {
+                "z" | "Z" if event.shift_key() => Some(Redo),
+                "z" | "Z" => Some(Undo),
+                "y" | "Y" => Some(Redo),
+                _ => None,
+            };
+        }
+        if event.ctrl_key() || event.meta_key() || event.alt_key() {
+            return None;
+        }
+        if event.key() == "Escape" {
+            return Some(Close);
+        }
+        if target.as_ref().is_some_and(|target| {
+            matches!(
+                target.tag_name().as_str(),
+                "INPUT" | "SELECT" | "TEXTAREA" | "BUTTON"
+            )
+        }) {
+            return None;
+        }
+        let key = event.key();
+        let shift = event.shift_key();
+        if let Some(date) = target
+            .and_then(|target| target.get_attribute("data-date"))
+            .and_then(|date| date.parse::<NaiveDate>().ok())
+        {
+            let weekday = date.weekday().days_since(start_of_week) as u64;
+            let focus = match key.as_str() {
+                "ArrowLeft" => date.checked_sub_days(Days::new(1)),
+                "ArrowRight" => date.checked_add_days(Days::new(1)),
+                "ArrowUp" => date.checked_sub_days(Days::new(7)),
+                "ArrowDown" => date.checked_add_days(Days::new(7)),
+                "Home" => date.checked_sub_days(Days::new(weekday)),
+                "End" => date.checked_add_days(Days::new(6 - weekday)),
+                "PageUp" => date.checked_sub_months(Months::new(if shift { 12 } else { 1 })),
+                "PageDown" => date.checked_add_months(Months::new(if shift { 12 } else { 1 })),
+                "Enter" | " " => return Some(Activate),
+                _ => None,
+            };
+            if let Some(focus) = focus {
+                return Some(Focus(focus));
+            }
+        }
+        Some(match key.as_str() {
+            "ArrowLeft" | "ArrowUp" | "PageUp" if shift => PrevYear,
+            "ArrowRight" | "ArrowDown" | "PageDown" if shift => NextYear,
+            "ArrowLeft" | "ArrowUp" | "PageUp" => PrevMonth,
+            "ArrowRight" | "ArrowDown" | "PageDown" => NextMonth,
+            "t" | "T" => Today,
+            "j" | "J" => Jump,
+            "s" | "S" => Styles,
+            "," => Settings,
+            "p" | "P" => Print,
+            "?" => Help,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Index, IndexMut},
+    rc::Rc,
+};
+
+use nongli::language::{Language, StaticTranslate};
+
+use crate::{roles::roles, translations};
+
+/// Window width in pixels below which the compact layout is used.
+pub const MOBILE_WIDTH: f64 = 768.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    Mobile,
+    Desktop,
+    Print,
+}
+
+impl Breakpoint {
+    pub const ALL: [Self; 3] = [Self::Mobile, Self::Desktop, Self::Print];
+
+    pub fn from_index(index: u32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+}
+
+impl StaticTranslate for Breakpoint {
+    fn static_translate(&self, language: Language) -> &'static str {
+        match self {
+            Self::Mobile => translations::Mobile.static_translate(language),
+            Self::Desktop => translations::Desktop.static_translate(language),
+            Self::Print => translations::Print.static_translate(language),
+        }
+    }
+}
+
+roles! {
+    pub enum Size {
+        CellWidth => "--size-cell-width", CellWidth;
+        CellHeight => "--size-cell-height", CellHeight;
+        HeaderHeight => "--size-header-height", HeaderHeight;
+        YearMargin => "--size-year-margin", YearMargin;
+        Text => "--size-text", TextSize;
+        TextWeekday => "--size-text-weekday", WeekdayTextSize;
+        TextWeekNumber => "--size-text-week-number", WeekNumberTextSize;
+        TextChinese => "--size-text-chinese", ChineseTextSize;
+        TextYear => "--size-text-year", YearTextSize;
+        TextMonth => "--size-text-month", MonthTextSize;
+    }
+    /// Values of all sizes for one breakpoint, indexed by [`Size`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Sizes;
+}
+
+impl Sizes {
+    pub fn new(breakpoint: Breakpoint) -> Self {
+        let values = match breakpoint {
+            Breakpoint::Mobile => [
+                "48px", "48px", "48px", "32px", "16px", "8px", "8px", "8px", "24px", "16px",
+            ],
+            Breakpoint::Desktop | Breakpoint::Print => [
+                "96px", "96px", "96px", "64px", "24px", "16px", "16px", "16px", "48px", "32px",
+            ],
+        };
+        Self(values.map(Rc::from))
+    }
+}
+
+/// The `--size-*` custom properties of the breakpoint, one per line.
+impl Display for Sizes {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        for size in Size::ALL {
+            writeln!(f, "{}: {};", size.variable(), self[size])?;
+        }
+        Ok(())
+    }
+}
+
+/// Sizes for each breakpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponsiveSizes {
+    pub mobile: Sizes,
+    pub desktop: Sizes,
+    pub print: Sizes,
+}
+
+impl Default for ResponsiveSizes {
+    fn default() -> Self {
+        Self {
+            mobile: Sizes::new(Breakpoint::Mobile),
+            desktop: Sizes::new(Breakpoint::Desktop),
+            print: Sizes::new(Breakpoint::Print),
+        }
+    }
+}
+
+impl Index<Breakpoint> for ResponsiveSizes {
+    type Output = Sizes;
+    fn index(&self, breakpoint: Breakpoint) -> &Sizes {
+        match breakpoint {
+            Breakpoint::Mobile => &self.mobile,
+            Breakpoint::Desktop => &self.desktop,
+            Breakpoint::Print => &self.print,
+        }
+    }
+}
+
+impl IndexMut<Breakpoint> for ResponsiveSizes {
+    fn index_mut(&mut self, breakpoint: Breakpoint) -> &mut Sizes {
+        match breakpoint {
+            Breakpoint::Mobile => &mut self.mobile,
+            Breakpoint::Desktop => &mut self.desktop,
+            Breakpoint::Print => &mut self.print,
+        }
+    }
+}
+
+impl ResponsiveSizes {
+    /// Style sheet with the sizes for the screen, followed by the ones for printing.
+    pub fn css(&self, is_mobile: bool) -> String {
+        let screen = if is_mobile {
+            Breakpoint::Mobile
+        } else {
+            Breakpoint::Desktop
+        };
+        format!(
+            ":root {{\n{}}}\n@media print {{\n:root {{\n{}}}\n}}\n",
+            self[screen], self.print,
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::{DateTime, Datelike, FixedOffset, Local, Month, Months, NaiveDate, TimeZone, Utc};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use nongli::{calendar::Cell, SolarTerm};
+
+use crate::astronomy;
+
+/// The longest delay in milliseconds `setTimeout` takes; longer ones fire at once.
+pub const MAX_TIMEOUT: u32 = i32::MAX as u32;
+
+/// Time zone in which dates and times are displayed, the browser's one if `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayZone(pub Option<Tz>);
+
+impl DisplayZone {
+    pub fn from_index(index: u32) -> Self {
+        Self(
+            index
+                .checked_sub(1)
+                .and_then(|index| TZ_VARIANTS.get(index as usize))
+                .copied(),
+        )
+    }
+
+    pub fn index(self) -> u32 {
+        self.0
+            .and_then(|tz| TZ_VARIANTS.iter().position(|variant| *variant == tz))
+            .map_or(0, |index| index as u32 + 1)
+    }
+
+    pub fn convert(self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self.0 {
+            Some(tz) => instant.with_timezone(&tz).fixed_offset(),
+            None => instant.with_timezone(&Local).fixed_offset(),
+        }
+    }
+
+    pub fn now(self) -> DateTime<FixedOffset> {
+        self.convert(Utc::now())
+    }
+
+    pub fn today(self) -> NaiveDate {
+        self.now().date_naive()
+    }
+
+    /// Milliseconds until the next midnight in this zone.
+    pub fn millis_until_tomorrow(self) -> u32 {
+        self.millis_until_day_after(Utc::now())
+    }
+
+    /// Milliseconds from `now` until the day after begins in this zone, at the first hour
+    /// that exists if a change to daylight saving time skips midnight.
+    fn millis_until_day_after(self, now: DateTime<Utc>) -> u32 {
+        let start = self
+            .convert(now)
+            .date_naive()
+            .succ_opt()
+            .and_then(|tomorrow| {
+                (0..24).find_map(|hour| {
+                    let time = tomorrow.and_hms_opt(hour, 0, 0)?;
+                    Some(match self.0 {
+                        Some(tz) => tz.from_local_datetime(&time).earliest()?.to_utc(),
+                        None => Local.from_local_datetime(&time).earliest()?.to_utc(),
+                    })
+                })
+            });
+        start.map_or(MAX_TIMEOUT, |start| {
+            (start - now)
+                .num_milliseconds()
+                .clamp(0, MAX_TIMEOUT as i64) as u32
+        })
+    }
+
+    pub fn format(self, instant: DateTime<Utc>) -> String {
+        self.convert(instant)
+            .format("%Y-%m-%d %H:%M (UTC%:z)")
+            .to_string()
+    }
+
+    /// Name and current offset of the zone, if it is not the given one of the browser.
+    pub fn label(self, browser_zone: Option<&str>) -> Option<String> {
+        let tz = self.0?;
+        (browser_zone != Some(tz.name()))
+            .then(|| format!("{} (UTC{})", tz.name(), self.now().format("%:z")))
+    }
+
+    /// Solar terms beginning in the given month, with the dates they fall on in this zone.
+    pub fn solar_terms(self, year: i32, month: Month) -> Vec<(NaiveDate, SolarTerm)> {
+        let ordinal = month.number_from_month() as u8 * 2 - 2;
+        (ordinal..ordinal + 2)
+            .filter_map(SolarTerm::from_ordinal)
+            .filter_map(|solar_term| {
+                astronomy::solar_term_instant(year, solar_term)
+                    .map(|instant| (self.convert(instant).date_naive(), solar_term))
+            })
+            .collect()
+    }
+
+    /// New moons falling on the days of the given month in this zone.
+    pub fn new_moons(self, year: i32, month: Month) -> Vec<DateTime<Utc>> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month.number_from_month(), 1) else {
+            return Vec::new();
+        };
+        let start = first.pred_opt().unwrap_or(first);
+        let end = first
+            .checked_add_months(Months::new(1))
+            .and_then(|date| date.succ_opt())
+            .unwrap_or(first);
+        astronomy::new_moons(
+            start.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+            end.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+        )
+        .into_iter()
+        .filter(|instant| {
+            let date = self.convert(*instant).date_naive();
+            date.year() == year && date.month() == month.number_from_month()
+        })
+        .collect()
+    }
+}
+
+/// Solar terms and new moons of a month in a zone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonthEvents {
+    zone: DisplayZone,
+    solar_terms: Vec<(NaiveDate, SolarTerm)>,
+    new_moons: Vec<DateTime<Utc>>,
+}
+
+impl MonthEvents {
+    pub fn new(zone: DisplayZone, year: i32, month: Month) -> Self {
+        Self {
+            zone,
+            solar_terms: zone.solar_terms(year, month),
+            new_moons: zone.new_moons(year, month),
+        }
+    }
+
+    /// The solar term and the new moon falling on a day of the month in the zone.
+    pub fn on(&self, date: NaiveDate) -> (Option<SolarTerm>, Option<DateTime<Utc>>) {
+        let solar_term = self
+            .solar_terms
+            .iter()
+            .find(|(day, _)| *day == date)
+            .map(|(_, solar_term)| *solar_term);
+        let new_moon = self
+            .new_moons
+            .iter()
+            .copied()
+            .find(|instant| self.zone.convert(*instant).date_naive() == date);
+        (solar_term, new_moon)
+    }
+
+    /// Moves the solar term of a cell to the day it falls on in the zone,
+    /// and finds the new moon on that day.
+    pub fn apply(&self, mut cell: Cell) -> (Cell, Option<DateTime<Utc>>) {
+        let (solar_term, new_moon) = self.on(cell.date);
+        cell.solar_term = solar_term;
+        (cell, new_moon)
+    }
+}
+
+pub fn time_zone_names() -> impl Iterator<Item = &'static str> {
+    TZ_VARIANTS.iter().map(|tz| tz.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn midnight_after_a_change_to_daylight_saving_time() {
+        // 01:30 EST on the day clocks go forward; midnight is 21.5 hours away.
+        let zone = DisplayZone(Some(Tz::America__New_York));
+        assert_eq!(
+            zone.millis_until_day_after(utc(2025, 3, 9, 6, 30)),
+            21 * 3_600_000 + 1_800_000
+        );
+    }
+
+    #[test]
+    fn day_starting_after_a_skipped_midnight() {
+        // Chile went from 00:00 to 01:00 on September 8, 2024.
+        let zone = DisplayZone(Some(Tz::America__Santiago));
+        assert_eq!(
+            zone.millis_until_day_after(utc(2024, 9, 8, 3, 0)),
+            3_600_000
+        );
+    }
+}
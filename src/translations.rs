@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use chrono::{Datelike, NaiveDate};
 use nongli::language::{
     Language::{self as Language0, *},
     StaticTranslate, Translate,
@@ -60,6 +61,8 @@ translate!(
     (Year, "Year", "年"),
     (Month, "Month", "月"),
     (HighlightToday, "Highlight Today", "高亮今日"),
+    (PrevYear, "Previous Year", "上一年"),
+    (NextYear, "Next Year", "下一年"),
 );
 
 translate!(
@@ -81,13 +84,61 @@ translate!(
     (WeekNumberColor, "Week Number Color", "周数颜色", "週數顏色"),
     (FestivalColor, "Festival Color", "节日颜色", "節日顏色"),
     (SolarTermColor, "Solar Term Color", "节气颜色", "節氣顏色"),
+    (Opacity, "Opacity", "不透明度", "不透明度"),
+    (PageBackground, "Background", "背景", "背景"),
+    (LowContrast, "Hard to Read", "难以阅读", "難以閱讀"),
+    (Undo, "Undo", "撤销", "復原"),
+    (Redo, "Redo", "重做", "取消復原"),
+    (
+        ResetToDefault,
+        "Reset to Default",
+        "恢复默认值",
+        "恢復預設值"
+    ),
+    (ResetAll, "Reset All", "全部恢复默认", "全部恢復預設"),
+    (FixContrast, "Use", "使用", "使用"),
+    (
+        InvalidColor,
+        "Enter a CSS color, such as #ff0000, red or rgb(255 0 0 / 50%).",
+        "请输入 CSS 颜色，例如 #ff0000、red 或 rgb(255 0 0 / 50%)。",
+        "請輸入 CSS 顏色，例如 #ff0000、red 或 rgb(255 0 0 / 50%)。"
+    ),
     (Font, "Font", "字体", "字體"),
+    (YearFont, "Year Font", "年字体", "年字體"),
+    (MonthFont, "Month Font", "月份字体", "月份字體"),
+    (WeekdayFont, "Weekday Font", "星期字体", "星期字體"),
+    (DayFont, "Day Number Font", "日期字体", "日期字體"),
+    (ChineseFont, "Chinese Calendar Font", "农历字体", "農曆字體"),
+    (SameAsFont, "Same as Font", "与字体相同", "與字體相同"),
+    (UploadedFonts, "Uploaded", "已上传", "已上傳"),
+    (InstalledFonts, "Installed", "已安装", "已安裝"),
+    (GoogleFonts, "Google Fonts", "Google 字体", "Google 字體"),
+    (
+        ListInstalledFonts,
+        "List Installed Fonts",
+        "列出已安装的字体",
+        "列出已安裝的字體"
+    ),
     (
         DownloadFontsAutomatically,
         "Download Fonts from Google Automatically",
         "自动从Google下载字体",
         "自動從Google下載字體",
     ),
+    (UploadFont, "Upload Font", "上传字体", "上傳字體"),
+    (
+        UnsupportedFont,
+        "The browser cannot use this file as a font.",
+        "浏览器无法将此文件用作字体。",
+        "瀏覽器無法將此檔案用作字體。"
+    ),
+    (RemoveFont, "Remove", "删除", "刪除"),
+    (
+        InvalidLength,
+        "Enter a number of zero or more.",
+        "请输入不小于零的数字。",
+        "請輸入不小於零的數字。"
+    ),
     (CellWidth, "Cell Width", "单元格宽度", "單元格寬度"),
     (CellHeight, "Cell Height", "单元格高度", "單元格高度"),
     (HeaderHeight, "Header Height", "标题高度", "標題高度"),
@@ -122,4 +173,206 @@ translate!(
         "從星期一開始"
     ),
     (ShowWeekNumbers, "Show Week Numbers", "显示周数", "顯示週數"),
+    (Close, "Close", "关闭", "關閉"),
+    (ExactTime, "Exact Time", "交节时刻", "交節時刻"),
+    (SolarLongitude, "Solar Longitude", "太阳黄经", "太陽黃經"),
+    (NewMoon, "New Moon", "新月", "新月"),
+    (TimeZone, "Time Zone", "时区", "時區"),
+    (LocalTimeZone, "Local Time Zone", "本地时区", "本地時區"),
+    (
+        AutoAdvance,
+        "Move to New Month Automatically",
+        "自动切换到新的月份",
+        "自動切換到新的月份"
+    ),
+    (
+        KeyboardShortcuts,
+        "Keyboard Shortcuts",
+        "键盘快捷键",
+        "鍵盤快速鍵"
+    ),
+    (
+        MoveBetweenDays,
+        "Move Between Days",
+        "在日期间移动",
+        "在日期間移動"
+    ),
+    (ShowDetails, "Show Details", "显示详情", "顯示詳情"),
+    (SizesFor, "Sizes For", "尺寸适用于", "尺寸適用於"),
+    (Mobile, "Mobile", "移动设备", "行動裝置"),
+    (Desktop, "Desktop", "桌面", "桌面"),
+    (PrintSetup, "Print Setup", "打印设置", "列印設定"),
+    (PaperSize, "Paper Size", "纸张大小", "紙張大小"),
+    (Custom, "Custom", "自定义", "自訂"),
+    (
+        PaperWidth,
+        "Paper Width (mm)",
+        "纸张宽度（毫米）",
+        "紙張寬度（公釐）"
+    ),
+    (
+        PaperHeight,
+        "Paper Height (mm)",
+        "纸张高度（毫米）",
+        "紙張高度（公釐）"
+    ),
+    (Orientation, "Orientation", "方向", "方向"),
+    (Portrait, "Portrait", "纵向", "直向"),
+    (Landscape, "Landscape", "横向", "橫向"),
+    (Margin, "Margin (mm)", "边距（毫米）", "邊距（公釐）"),
+    (
+        ScaleToFit,
+        "Fit to Page",
+        "缩放以适合页面",
+        "縮放以符合頁面"
+    ),
+    (
+        SeparatePrintColors,
+        "Separate Colors for Printing",
+        "打印时使用单独的颜色",
+        "列印時使用單獨的顏色"
+    ),
+    (FromYear, "From Year", "起始年", "起始年"),
+    (FromMonth, "From Month", "起始月", "起始月"),
+    (ToYear, "To Year", "结束年", "結束年"),
+    (ToMonth, "To Month", "结束月", "結束月"),
+    (PrintRange, "Print Range", "打印范围", "列印範圍"),
+    (Export, "Export", "导出", "匯出"),
+    (ExportMonths, "Months", "月份", "月份"),
+    (CurrentMonth, "Current Month", "本月", "本月"),
+    (WholeYear, "Whole Year", "全年", "全年"),
+    (
+        CurrentMonthAsWebPage,
+        "The current month as a web page",
+        "将本月导出为网页",
+        "將本月匯出為網頁"
+    ),
+    (
+        ImageResolution,
+        "Image Resolution (DPI)",
+        "图片分辨率（DPI）",
+        "圖片解析度（DPI）"
+    ),
+    (PdfFont, "Font for PDF", "PDF 字体", "PDF 字型"),
+    (
+        UnreadableFont,
+        "This file is not a TrueType or OpenType font.",
+        "此文件不是 TrueType 或 OpenType 字体。",
+        "此檔案不是 TrueType 或 OpenType 字型。"
+    ),
+    (
+        FontFace,
+        "Font in collection",
+        "字体集中的字体",
+        "字型集中的字型"
+    ),
+    (
+        Cff2Font,
+        "Variable fonts with CFF2 outlines cannot be embedded in a PDF.",
+        "使用 CFF2 轮廓的可变字体无法嵌入 PDF。",
+        "使用 CFF2 輪廓的可變字型無法嵌入 PDF。"
+    ),
+    (
+        ExportFailed,
+        "The file could not be saved.",
+        "无法保存文件。",
+        "無法儲存檔案。"
+    ),
+    (
+        ChooseFontFirst,
+        "Choose a font for the PDF first.",
+        "请先选择 PDF 使用的字体。",
+        "請先選擇 PDF 使用的字型。"
+    ),
+    (
+        ReversedRange,
+        "The last month comes before the first one, so nothing is printed.",
+        "结束月份早于开始月份，不会打印任何内容。",
+        "結束月份早於開始月份，不會列印任何內容。"
+    ),
+    (
+        WheelNavigation,
+        "Change Month with Mouse Wheel",
+        "使用鼠标滚轮切换月份",
+        "使用滑鼠滾輪切換月份"
+    ),
 );
+
+/// Value of the `lang` attribute for the language.
+pub fn language_tag(language: Language0) -> &'static str {
+    match language {
+        English => "en",
+        ChineseSimplified => "zh-Hans",
+        ChineseTraditional => "zh-Hant",
+    }
+}
+
+/// The language of a `lang` attribute given by [`language_tag`].
+pub fn language_from_tag(tag: &str) -> Option<Language0> {
+    match tag {
+        "en" => Some(English),
+        "zh-Hans" => Some(ChineseSimplified),
+        "zh-Hant" => Some(ChineseTraditional),
+        _ => None,
+    }
+}
+
+/// A date with its weekday, such as "Saturday, January 11, 2025".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FullDate(pub NaiveDate);
+
+impl Translate for FullDate {
+    fn translate(&self, language: Language0, f: &mut Formatter) -> FmtResult {
+        let date = self.0;
+        let weekday = date.weekday();
+        let weekday = weekday.translate_adapter(language);
+        match language {
+            English => write!(
+                f,
+                "{weekday}, {} {}, {}",
+                chrono::Month::try_from(date.month() as u8).unwrap().name(),
+                date.day(),
+                date.year(),
+            ),
+            _ => write!(
+                f,
+                "{}年{}月{}日 {weekday}",
+                date.year(),
+                date.month(),
+                date.day(),
+            ),
+        }
+    }
+}
+
+/// The number of a week, such as "Week 2".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekNumber(pub u32);
+
+impl Translate for WeekNumber {
+    fn translate(&self, language: Language0, f: &mut Formatter) -> FmtResult {
+        match language {
+            English => write!(f, "Week {}", self.0),
+            ChineseSimplified => write!(f, "第{}周", self.0),
+            ChineseTraditional => write!(f, "第{}週", self.0),
+        }
+    }
+}
+
+/// Tells that a picture was saved at a lower resolution than asked for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReducedResolution(pub i32);
+
+impl Translate for ReducedResolution {
+    fn translate(&self, language: Language0, f: &mut Formatter) -> FmtResult {
+        match language {
+            English => write!(
+                f,
+                "The picture would have been too large, so it was saved at {} DPI.",
+                self.0,
+            ),
+            ChineseSimplified => write!(f, "图片过大，已改为以 {} DPI 保存。", self.0),
+            ChineseTraditional => write!(f, "圖片過大，已改為以 {} DPI 儲存。", self.0),
+        }
+    }
+}
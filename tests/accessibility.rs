@@ -0,0 +1,162 @@
+//! What screen readers and keyboards rely on in the grid and the dialogs.
+//!
+//! Run in a browser with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use std::time::Duration;
+
+use calendar::{
+    app::App,
+    dialog::DialogBox,
+    month::{self, MonthTable},
+    time_zone::DisplayZone,
+};
+use chrono::{Datelike, Month, Months, NaiveDate};
+use nongli::{
+    calendar::Options,
+    language::{Language, MonthTitle, Translate},
+    ChineseDate,
+};
+use wasm_bindgen_test::*;
+use web_sys::{
+    wasm_bindgen::JsCast, Document, Element, HtmlElement, KeyboardEvent, KeyboardEventInit,
+};
+use yew::{platform::time::sleep, prelude::*, Renderer};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn document() -> Document {
+    web_sys::window().unwrap().document().unwrap()
+}
+
+/// Renders the component into a new element of the page, and waits for its effects.
+async fn mount<C: BaseComponent>(props: C::Properties) -> Element {
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    sleep(Duration::from_millis(50)).await;
+    root
+}
+
+fn find(root: &Element, selector: &str) -> HtmlElement {
+    root.query_selector(selector)
+        .unwrap()
+        .unwrap_or_else(|| panic!("no {selector}"))
+        .dyn_into()
+        .unwrap()
+}
+
+fn active_id() -> String {
+    document()
+        .active_element()
+        .map(|element| element.id())
+        .unwrap_or_default()
+}
+
+fn press_tab(target: &HtmlElement, shift: bool) {
+    let init = KeyboardEventInit::new();
+    init.set_key("Tab");
+    init.set_shift_key(shift);
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn grid_labels_every_day() {
+    let today = NaiveDate::from_ymd_opt(2025, 1, 29).unwrap();
+    let root = mount::<MonthTable>(month::Props {
+        year: 2025,
+        month: Month::January,
+        today,
+        highlight_today: true,
+        options: Options {
+            language: Language::English,
+            enable_chinese: true,
+            start_on_monday: false,
+            week_number: false,
+            color: false,
+        },
+        time_zone: DisplayZone(Some(chrono_tz::Asia::Shanghai)),
+        class: Classes::new(),
+        focus_target: Some(today),
+        onselect: None,
+    })
+    .await;
+
+    find(&root, "table[role=\"grid\"]");
+    let days = root
+        .query_selector_all("td[role=\"gridcell\"][aria-label]")
+        .unwrap();
+    assert_eq!(days.length(), 31);
+
+    let current = find(&root, "td[aria-current=\"date\"]");
+    assert_eq!(
+        root.query_selector_all("[aria-current]").unwrap().length(),
+        1
+    );
+    let label = current.get_attribute("aria-label").unwrap();
+    let chinese_date = ChineseDate::from_gregorian(&today)
+        .unwrap()
+        .translate_to_string(Language::English);
+    assert!(label.starts_with("Wednesday, January 29, 2025"), "{label}");
+    assert!(label.contains(&chinese_date), "{label}");
+}
+
+#[function_component]
+fn TrappingDialog() -> Html {
+    html! { <DialogBox title="Title" close_title="Close" onclose={ Callback::noop() }>
+        <button id="middle">{"Middle"}</button>
+        <button id="last">{"Last"}</button>
+        <button disabled=true>{"Disabled"}</button>
+        <button hidden=true>{"Hidden"}</button>
+        <div style="display: none"><input /></div>
+    </DialogBox> }
+}
+
+#[wasm_bindgen_test]
+async fn dialog_is_labelled_and_keeps_the_focus() {
+    let root = mount::<TrappingDialog>(()).await;
+
+    let dialog = find(&root, "[role=\"dialog\"]");
+    assert_eq!(dialog.get_attribute("aria-modal").as_deref(), Some("true"));
+    let title_id = dialog.get_attribute("aria-labelledby").unwrap();
+    let title = document().get_element_by_id(&title_id).unwrap();
+    assert!(title.text_content().unwrap().starts_with("Title"));
+
+    let close = find(&root, "button.close");
+    close.set_id("close");
+    assert_eq!(active_id(), "close");
+
+    let last = find(&root, "#last");
+    last.focus().unwrap();
+    press_tab(&last, false);
+    assert_eq!(active_id(), "close");
+
+    press_tab(&close, true);
+    assert_eq!(active_id(), "last");
+
+    find(&root, "#middle").focus().unwrap();
+    press_tab(&find(&root, "#middle"), false);
+    assert_eq!(active_id(), "middle");
+}
+
+#[wasm_bindgen_test]
+async fn month_changes_are_announced() {
+    let root = mount::<App>(Default::default()).await;
+    let today = DisplayZone(None).today();
+    let next = today.with_day(1).unwrap() + Months::new(1);
+
+    find(&root, "button[aria-label=\"Next Month\"]").click();
+    sleep(Duration::from_millis(50)).await;
+
+    let expected = MonthTitle {
+        year: next.year(),
+        month: Month::try_from(next.month() as u8).unwrap(),
+        enable_chinese: false,
+    }
+    .translate_to_string(Language::English);
+    let live = find(&root, "[aria-live=\"polite\"]");
+    assert_eq!(live.text_content().unwrap(), expected);
+}
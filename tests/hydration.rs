@@ -0,0 +1,58 @@
+//! Pages rendered as `prerender` does, then hydrated as `main` does.
+//!
+//! Run in a browser with `wasm-pack test --headless --firefox -- --features ssr`.
+#![cfg(all(target_arch = "wasm32", feature = "ssr"))]
+
+use std::time::Duration;
+
+use calendar::{
+    app::{App, Props},
+    time_zone::DisplayZone,
+};
+use chrono::{Datelike, Month};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::{platform::time::sleep, LocalServerRenderer, Renderer};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn hydrate(props: Props) -> Element {
+    let page = LocalServerRenderer::<App>::with_props(props).render().await;
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    root.set_inner_html(&page);
+    document.body().unwrap().append_child(&root).unwrap();
+    Renderer::<App>::with_root_and_props(root.clone(), props).hydrate();
+    sleep(Duration::from_millis(100)).await;
+    root
+}
+
+fn today_button(root: &Element) -> Option<Element> {
+    root.query_selector("button[aria-label=\"Today\"]").unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn hydrates_the_current_month() {
+    let today = DisplayZone(None).today();
+    let month = Month::try_from(today.month() as u8).unwrap();
+    let root = hydrate(Props {
+        month: Some((today.year(), month)),
+        ..Props::default()
+    })
+    .await;
+    assert!(root
+        .query_selector("[aria-current=\"date\"]")
+        .unwrap()
+        .is_some());
+    assert!(today_button(&root).is_none());
+}
+
+#[wasm_bindgen_test]
+async fn hydrates_another_month() {
+    let root = hydrate(Props {
+        month: Some((2000, Month::January)),
+        ..Props::default()
+    })
+    .await;
+    assert!(today_button(&root).is_some());
+}
@@ -0,0 +1,24 @@
+//! Pre-rendered pages, which the app hydrates whatever the date and query string are.
+#![cfg(feature = "ssr")]
+
+use calendar::app::{App, Props};
+use chrono::Month;
+use yew::ServerRenderer;
+
+fn render(props: Props) -> String {
+    futures::executor::block_on(ServerRenderer::<App>::with_props(move || props).render())
+}
+
+#[test]
+fn leaves_out_what_depends_on_the_browser() {
+    let page = render(Props {
+        month: Some((2000, Month::January)),
+        ..Props::default()
+    });
+    assert!(page.contains("arrow_back"));
+    assert!(page.contains("aria-label=\"Next Month\""));
+    // The Today button and the kiosk clock need the date, and kiosk mode the query string.
+    assert!(!page.contains("aria-label=\"Today\""));
+    assert!(!page.contains("class=\"kiosk-status\""));
+    assert!(!page.contains("class=\"kiosk-shield\""));
+}